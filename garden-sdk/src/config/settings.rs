@@ -0,0 +1,211 @@
+use crate::models::address::ChainAddress;
+use starknet::core::chain_id;
+use starknet_crypto::Felt;
+
+// A Starknet network the CLI can sign for
+#[derive(Debug)]
+pub struct StarknetNetwork {
+    /// Garden chain names that refer to this network
+    pub chains: &'static [&'static str],
+    /// SNIP-12 domain chain id, or `None` to resolve it from `rpc_url` once per run
+    pub chain_id: Option<Felt>,
+    /// JSON-RPC endpoint, only needed for live account queries
+    pub rpc_url: Option<&'static str>,
+}
+
+pub const STARKNET_NETWORKS: &[StarknetNetwork] = &[
+    StarknetNetwork {
+        chains: &["starknet_sepolia"],
+        chain_id: Some(chain_id::SEPOLIA),
+        rpc_url: Some(
+            "https://starknet-sepolia.g.alchemy.com/starknet/version/rpc/v0_8/zN3JM2LnBeD4lFHMlO_iA8IoQA8Ws9_r",
+        ),
+    },
+    StarknetNetwork {
+        chains: &["starknet", "starknet_mainnet"],
+        chain_id: Some(chain_id::MAINNET),
+        rpc_url: None,
+    },
+];
+
+pub fn starknet_network(chain: &str) -> Option<&'static StarknetNetwork> {
+    STARKNET_NETWORKS
+        .iter()
+        .find(|network| network.chains.contains(&chain))
+}
+
+// An EVM network the CLI can send transactions on
+#[derive(Debug)]
+pub struct EvmNetwork {
+    /// Garden chain names that refer to this network
+    pub chains: &'static [&'static str],
+    /// JSON-RPC endpoint used for direct HTLC calls
+    pub rpc_url: &'static str,
+}
+
+pub const EVM_NETWORKS: &[EvmNetwork] = &[
+    EvmNetwork {
+        chains: &["arbitrum_sepolia"],
+        rpc_url: "https://sepolia-rollup.arbitrum.io/rpc",
+    },
+    EvmNetwork {
+        chains: &["ethereum_sepolia"],
+        rpc_url: "https://ethereum-sepolia-rpc.publicnode.com",
+    },
+];
+
+pub fn evm_network(chain: &str) -> Option<&'static EvmNetwork> {
+    EVM_NETWORKS
+        .iter()
+        .find(|network| network.chains.contains(&chain))
+}
+
+// Decimals of an asset, to turn smallest-unit amounts into whole tokens
+#[derive(Debug)]
+pub struct AssetDecimals {
+    pub chain: &'static str,
+    /// HTLC asset address as it appears in order pairs
+    pub asset: &'static str,
+    pub decimals: u32,
+}
+
+/// Assets whose USD value can be computed in ledger exports; add an asset here
+/// to fill its `*_value_usd` columns
+pub const ASSET_DECIMALS: &[AssetDecimals] = &[AssetDecimals {
+    chain: "arbitrum_sepolia",
+    asset: "0x795Dcb58d1cd4789169D5F938Ea05E17ecEB68cA",
+    decimals: 8,
+}];
+
+pub fn asset_decimals(chain: &str, asset: &ChainAddress) -> Option<u32> {
+    ASSET_DECIMALS
+        .iter()
+        .find(|known| {
+            known.chain == chain && known.asset.parse::<ChainAddress>().ok().as_ref() == Some(asset)
+        })
+        .map(|known| known.decimals)
+}
+
+// Relayer that submits redeems on a destination chain
+#[derive(Debug)]
+pub struct RedeemRelayer {
+    /// Garden chain names the relayer redeems on
+    pub chains: &'static [&'static str],
+    /// Base URL; redeems are posted to `{url}/redeem`
+    pub url: &'static str,
+}
+
+pub const TESTNET_REDEEM_RELAYERS: &[RedeemRelayer] = &[
+    RedeemRelayer {
+        chains: &["starknet_sepolia"],
+        url: "https://starknet-relayer.hashira.io",
+    },
+    RedeemRelayer {
+        chains: &["arbitrum_sepolia", "ethereum_sepolia"],
+        url: "https://evm-relay-stage.hashira.io",
+    },
+];
+
+// Order parameters for orders leaving or arriving on a chain
+#[derive(Debug)]
+pub struct ChainOrderDefaults {
+    /// Garden chain names these defaults apply to
+    pub chains: &'static [&'static str],
+    /// Chain whose block number HTLC timelocks count, when it is not the chain itself
+    ///
+    /// `block.number` on Arbitrum is the L1 block number, so Arbitrum timelocks run
+    /// on Ethereum blocks.
+    pub timelock_chain: Option<&'static str>,
+    /// Average interval of the block number timelocks count, used to estimate
+    /// wall-clock durations
+    pub block_time_secs: f64,
+    /// Source HTLC timelock in blocks, used when the chain is the source
+    pub timelock: u64,
+    /// Largest timelock the chain's HTLC can enforce, when the chain caps it
+    pub max_timelock: Option<u64>,
+    /// Order fee, used when the chain is the source
+    pub fee: u64,
+    /// Confirmations the solver waits for, used when the chain is the destination
+    pub min_destination_confirmations: u64,
+    /// Minutes an attested order stays valid, used when the chain is the source
+    pub deadline_minutes: u64,
+}
+
+pub const CHAIN_ORDER_DEFAULTS: &[ChainOrderDefaults] = &[
+    ChainOrderDefaults {
+        chains: &["arbitrum_sepolia"],
+        timelock_chain: Some("ethereum_sepolia"),
+        block_time_secs: 12.0,
+        timelock: 7300,
+        max_timelock: None,
+        fee: 1,
+        min_destination_confirmations: 2,
+        deadline_minutes: 10,
+    },
+    ChainOrderDefaults {
+        chains: &["arbitrum"],
+        timelock_chain: Some("ethereum"),
+        block_time_secs: 12.0,
+        timelock: 7300,
+        max_timelock: None,
+        fee: 1,
+        min_destination_confirmations: 2,
+        deadline_minutes: 10,
+    },
+    ChainOrderDefaults {
+        chains: &["ethereum_sepolia", "ethereum"],
+        timelock_chain: None,
+        block_time_secs: 12.0,
+        timelock: 7200,
+        max_timelock: None,
+        fee: 1,
+        min_destination_confirmations: 2,
+        deadline_minutes: 10,
+    },
+    ChainOrderDefaults {
+        chains: &["starknet_sepolia", "starknet", "starknet_mainnet"],
+        timelock_chain: None,
+        block_time_secs: 30.0,
+        timelock: 7300,
+        max_timelock: None,
+        fee: 1,
+        min_destination_confirmations: 2,
+        deadline_minutes: 10,
+    },
+    ChainOrderDefaults {
+        chains: &["bitcoin_testnet", "bitcoin"],
+        timelock_chain: None,
+        block_time_secs: 600.0,
+        timelock: 144,
+        // The HTLC refunds through OP_CHECKSEQUENCEVERIFY, whose relative lock-time
+        // in blocks is a 16-bit field (BIP 68)
+        max_timelock: Some(0xFFFF),
+        fee: 1,
+        min_destination_confirmations: 1,
+        deadline_minutes: 30,
+    },
+];
+
+// Used for chains missing from CHAIN_ORDER_DEFAULTS
+pub const FALLBACK_ORDER_DEFAULTS: ChainOrderDefaults = ChainOrderDefaults {
+    chains: &[],
+    timelock_chain: None,
+    block_time_secs: 12.0,
+    timelock: 7300,
+    max_timelock: None,
+    fee: 1,
+    min_destination_confirmations: 2,
+    deadline_minutes: 10,
+};
+
+pub fn chain_order_defaults(chain: &str) -> &'static ChainOrderDefaults {
+    CHAIN_ORDER_DEFAULTS
+        .iter()
+        .find(|defaults| defaults.chains.contains(&chain))
+        .unwrap_or(&FALLBACK_ORDER_DEFAULTS)
+}
+
+// Chain whose block height decides when an HTLC on `chain` expires
+pub fn timelock_chain(chain: &str) -> &str {
+    chain_order_defaults(chain).timelock_chain.unwrap_or(chain)
+}
@@ -0,0 +1,32 @@
+//! Garden SDK: quote, attest, create, initiate, redeem, refund and track Garden Finance
+//! cross-chain orders.
+//!
+//! [`OrderService`] drives an order through its lifecycle against the Garden relay and
+//! orderbook APIs through a [`GardenApi`] implementation, using [`QuoteService`] for quotes
+//! and attestations. Signing and offline signature checks live in
+//! [`services::signature_service`].
+//!
+//! ```no_run
+//! # async fn example() -> eyre::Result<()> {
+//! use garden_sdk::OrderService;
+//!
+//! let orders = OrderService::new();
+//! let pair = "arbitrum_sepolia:0x795Dcb58d1cd4789169D5F938Ea05E17ecEB68cA::starknet_sepolia:0x58ea74e863bc9a761aa20701e04b65854f5614db3eb79b2d3a76a8771694c02";
+//! let quote = orders.quotes().quote(pair, "10000", false).await?;
+//! let (order_id, secret) = orders.create_order(&quote).await?;
+//! println!("{:?}", orders.order_status(&order_id).await?);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod config;
+pub mod models;
+pub mod services;
+pub mod utils;
+
+pub use services::garden_api::{GardenApi, ReqwestGardenApi};
+pub use services::order_service::OrderService;
+pub use services::quote_service::QuoteService;
+
+#[doc(hidden)]
+pub mod test_support;
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::str::FromStr;
+
+use alloy::primitives::Address;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use starknet::core::types::Felt;
+
+/// An address on any chain Garden supports
+///
+/// EVM addresses are 20 byte hex strings, Starknet addresses are felts and
+/// anything else (Bitcoin addresses, `primary` assets) is kept verbatim.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChainAddress {
+    Evm(Address),
+    Starknet(Felt),
+    Other(String),
+}
+
+impl ChainAddress {
+    pub fn as_evm(&self) -> Option<Address> {
+        match self {
+            Self::Evm(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    pub fn as_starknet(&self) -> Option<Felt> {
+        match self {
+            Self::Starknet(address) => Some(*address),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ChainAddress {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() == 42 {
+            if let Ok(address) = Address::from_str(s) {
+                return Ok(Self::Evm(address));
+            }
+        }
+        if s.starts_with("0x") {
+            if let Ok(address) = Felt::from_hex(s) {
+                return Ok(Self::Starknet(address));
+            }
+        }
+        Ok(Self::Other(s.to_string()))
+    }
+}
+
+impl fmt::Display for ChainAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Evm(address) => write!(f, "{}", address),
+            Self::Starknet(address) => write!(f, "{:#066x}", address),
+            Self::Other(address) => f.write_str(address),
+        }
+    }
+}
+
+impl Serialize for ChainAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|never| match never {}))
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+
+use crate::models::address::ChainAddress;
+use crate::models::order::OrderStatus;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Response of the `orders/id/matched/{order_id}` endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderDetails {
+    pub result: MatchedOrder,
+    /// Fields the API sent that this model does not know about
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// An order together with the swaps the solver matched it to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchedOrder {
+    pub source_swap: SwapData,
+    pub destination_swap: SwapData,
+    pub create_order: CreateOrderData,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl MatchedOrder {
+    /// Current lifecycle stage, from the furthest step either swap reached
+    pub fn status(&self) -> OrderStatus {
+        if self.source_swap.is_refunded() {
+            OrderStatus::Refunded
+        } else if self.destination_swap.is_redeemed() {
+            OrderStatus::Redeemed
+        } else if self.destination_swap.is_initiated() {
+            OrderStatus::CounterpartyInitiated
+        } else if self.source_swap.is_initiated() {
+            OrderStatus::Initiated
+        } else {
+            OrderStatus::Created
+        }
+    }
+
+    /// Unix time the orderbook created the order, when it reports one
+    pub fn created_at(&self) -> Option<i64> {
+        let created_at = self.create_order.extra.get("created_at")?.as_str()?;
+        chrono::DateTime::parse_from_rfc3339(created_at)
+            .ok()
+            .map(|created_at| created_at.timestamp())
+    }
+
+    /// On-chain steps of both swaps in the order they happen
+    pub fn timeline(&self) -> Vec<SwapEvent> {
+        let steps = [
+            (SwapLeg::Source, SwapStep::Initiated),
+            (SwapLeg::Destination, SwapStep::Initiated),
+            (SwapLeg::Destination, SwapStep::Redeemed),
+            (SwapLeg::Source, SwapStep::Redeemed),
+            (SwapLeg::Source, SwapStep::Refunded),
+            (SwapLeg::Destination, SwapStep::Refunded),
+        ];
+        steps
+            .into_iter()
+            .filter_map(|(leg, step)| {
+                let swap = match leg {
+                    SwapLeg::Source => &self.source_swap,
+                    SwapLeg::Destination => &self.destination_swap,
+                };
+                let (tx_hash, block_number) = match step {
+                    SwapStep::Initiated => (&swap.initiate_tx_hash, swap.initiate_block_number),
+                    SwapStep::Redeemed => (&swap.redeem_tx_hash, swap.redeem_block_number),
+                    SwapStep::Refunded => (&swap.refund_tx_hash, swap.refund_block_number),
+                };
+                has_tx(tx_hash).then(|| SwapEvent {
+                    leg,
+                    step,
+                    chain: swap.chain.clone(),
+                    tx_hash: tx_hash.clone().unwrap_or_default(),
+                    block_number,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether a solver picked the order up and opened its destination swap
+    pub fn is_matched(&self) -> bool {
+        !self.destination_swap.chain.is_empty()
+            && self.destination_swap.chain == self.create_order.destination_chain
+    }
+}
+
+/// Which leg of a matched order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwapLeg {
+    Source,
+    Destination,
+}
+
+/// On-chain step of a swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SwapStep {
+    Initiated,
+    Redeemed,
+    Refunded,
+}
+
+/// One transaction in an order's timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwapEvent {
+    pub leg: SwapLeg,
+    pub step: SwapStep,
+    pub chain: String,
+    pub tx_hash: String,
+    pub block_number: Option<u64>,
+}
+
+/// One leg of a matched order
+///
+/// Transaction hashes, block numbers and the secret stay `None` until the
+/// corresponding step happened on chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapData {
+    pub redeemer: ChainAddress,
+    pub timelock: u64,
+    pub amount: BigDecimal,
+    pub secret_hash: String,
+    pub chain: String,
+    pub asset: ChainAddress,
+    pub initiator: ChainAddress,
+    /// Id of the swap in the HTLC contract, needed to redeem or refund on chain
+    #[serde(default, deserialize_with = "non_empty")]
+    pub swap_id: Option<String>,
+    #[serde(default)]
+    pub filled_amount: Option<BigDecimal>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub secret: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub initiate_tx_hash: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub redeem_tx_hash: Option<String>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub refund_tx_hash: Option<String>,
+    #[serde(default, deserialize_with = "block_number")]
+    pub initiate_block_number: Option<u64>,
+    #[serde(default, deserialize_with = "block_number")]
+    pub redeem_block_number: Option<u64>,
+    #[serde(default, deserialize_with = "block_number")]
+    pub refund_block_number: Option<u64>,
+    #[serde(default)]
+    pub required_confirmations: u64,
+    #[serde(default)]
+    pub current_confirmations: u64,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl SwapData {
+    pub fn is_initiated(&self) -> bool {
+        has_tx(&self.initiate_tx_hash)
+    }
+
+    pub fn is_redeemed(&self) -> bool {
+        has_tx(&self.redeem_tx_hash)
+    }
+
+    pub fn is_refunded(&self) -> bool {
+        has_tx(&self.refund_tx_hash)
+    }
+}
+
+/// The order as it was submitted to `create-order`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOrderData {
+    pub create_id: String,
+    #[serde(default, deserialize_with = "block_number")]
+    pub block_number: Option<u64>,
+    pub source_chain: String,
+    pub destination_chain: String,
+    pub source_asset: ChainAddress,
+    pub destination_asset: ChainAddress,
+    pub initiator_source_address: ChainAddress,
+    pub initiator_destination_address: ChainAddress,
+    pub source_amount: BigDecimal,
+    pub destination_amount: BigDecimal,
+    pub fee: BigDecimal,
+    pub nonce: BigDecimal,
+    pub min_destination_confirmations: u64,
+    pub timelock: u64,
+    pub secret_hash: String,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    pub additional_data: CreateOrderAdditionalData,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Quote and attestation data stored with a created order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOrderAdditionalData {
+    pub strategy_id: String,
+    #[serde(default)]
+    pub input_token_price: Option<f64>,
+    #[serde(default)]
+    pub output_token_price: Option<f64>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub sig: Option<String>,
+    #[serde(default)]
+    pub deadline: Option<i64>,
+    #[serde(default, deserialize_with = "non_empty")]
+    pub tx_hash: Option<String>,
+    #[serde(default)]
+    pub is_blacklisted: bool,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+// Whether the API reported a transaction hash for a swap step
+fn has_tx(tx_hash: &Option<String>) -> bool {
+    tx_hash.as_deref().is_some_and(|hash| hash != "0x")
+}
+
+// The API sends "" as often as null for values it does not have yet
+fn non_empty<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.filter(|s| !s.trim().is_empty()))
+}
+
+// Block numbers arrive as strings, numbers, null or "0" for "not yet"
+fn block_number<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    let number = match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => None,
+        Some(Value::Number(number)) => number.as_u64(),
+        Some(Value::String(text)) if text.trim().is_empty() => None,
+        Some(Value::String(text)) => Some(text.trim().parse().map_err(serde::de::Error::custom)?),
+        Some(other) => {
+            return Err(serde::de::Error::custom(format!(
+                "invalid block number: {}",
+                other
+            )));
+        }
+    };
+    Ok(number.filter(|&number| number != 0))
+}
@@ -0,0 +1,7 @@
+pub mod additional_data;
+pub mod address;
+pub mod matched_order;
+pub mod order;
+pub mod order_params;
+pub mod quote;
+pub mod secret;
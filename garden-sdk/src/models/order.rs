@@ -1,24 +1,8 @@
 use crate::models::additional_data::{AdditonalData, SignableAdditionalData};
-use alloy::sol;
+use crate::models::secret::SecretHash;
 use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 
-sol! {
-    struct Initiate {
-        address redeemer;
-        uint256 timelock;
-        uint256 amount;
-        bytes32 secretHash;
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct InitiateRequest {
-    pub order_id: String,
-    pub signature: String,
-    pub perform_on: String,
-}
-
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Status {
     Ok,
@@ -33,6 +17,17 @@ pub struct ApiResponse<T> {
     pub error: Option<String>,
 }
 
+/// One page of a paginated orderbook listing
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Paginated<T> {
+    pub data: Vec<T>,
+    /// 1-based page number
+    pub page: u32,
+    pub total_pages: u32,
+    pub total_items: u64,
+    pub per_page: u32,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AttestedData {
     pub sig: String,
@@ -51,6 +46,52 @@ pub struct AtttestedResult {
     pub additional_data: AttestedData,
 }
 
+/// Lifecycle stage of an order, derived from its matched swaps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderStatus {
+    /// Created and matched, source swap not yet initiated
+    Created,
+    /// Source swap initiated by the user
+    Initiated,
+    /// Destination swap initiated by the solver, ready to redeem
+    CounterpartyInitiated,
+    /// Destination swap redeemed
+    Redeemed,
+    /// Source swap refunded
+    Refunded,
+}
+
+impl std::str::FromStr for OrderStatus {
+    type Err = eyre::Report;
+
+    // Accepts the variant name in any case, with or without underscores
+    fn from_str(status: &str) -> eyre::Result<Self> {
+        match status.to_lowercase().replace(['_', '-'], "").as_str() {
+            "created" => Ok(Self::Created),
+            "initiated" => Ok(Self::Initiated),
+            "counterpartyinitiated" => Ok(Self::CounterpartyInitiated),
+            "redeemed" => Ok(Self::Redeemed),
+            "refunded" => Ok(Self::Refunded),
+            _ => Err(eyre::eyre!(
+                "Unknown order status: {} (expected created, initiated, counterparty_initiated, redeemed or refunded)",
+                status
+            )),
+        }
+    }
+}
+
+/// Outcome of checking a stored order against the orderbook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderVerification {
+    /// Matched and locked on the hash of the stored secret
+    Verified(OrderStatus),
+    /// Matched, but the orderbook holds a different hash than the stored secret's
+    SecretHashMismatch {
+        local: SecretHash,
+        remote: SecretHash,
+    },
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Order<T = AdditonalData> {
     pub source_chain: String,
@@ -109,4 +150,27 @@ impl Order<AdditonalData> {
             additional_data: signable_additional_data,
         }
     }
+
+    // Payload sent to the attestation endpoint: the order minus the attested fields
+    pub fn attestation_payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "source_chain": self.source_chain,
+            "destination_chain": self.destination_chain,
+            "source_asset": self.source_asset,
+            "destination_asset": self.destination_asset,
+            "initiator_source_address": self.initiator_source_address,
+            "initiator_destination_address": self.initiator_destination_address,
+            "source_amount": self.source_amount.to_string(),
+            "destination_amount": self.destination_amount.to_string(),
+            "fee": self.fee.to_string(),
+            "nonce": self.nonce.to_string(),
+            "min_destination_confirmations": self.min_destination_confirmations,
+            "timelock": self.timelock,
+            "secret_hash": self.secret_hash,
+            "additional_data": {
+                "strategy_id": self.additional_data.strategy_id,
+                "bitcoin_optional_recipient": self.additional_data.bitcoin_optional_recipient,
+            }
+        })
+    }
 }
@@ -0,0 +1,306 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::config::settings::chain_order_defaults;
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+/// Timelock, fee, confirmations and deadline of a new order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderParams {
+    /// Source HTLC timelock in source chain blocks
+    pub timelock: u64,
+    pub fee: u64,
+    pub min_destination_confirmations: u64,
+    /// Minutes the attested order stays valid
+    pub deadline_minutes: u64,
+}
+
+/// Values that replace the per-chain defaults, e.g. from CLI flags
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrderParamOverrides {
+    pub timelock: Option<u64>,
+    pub fee: Option<u64>,
+    pub min_destination_confirmations: Option<u64>,
+    pub deadline_minutes: Option<u64>,
+}
+
+/// Inclusive bounds on one order parameter; a missing side is unbounded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParamRange {
+    #[serde(default)]
+    pub min: Option<u64>,
+    #[serde(default)]
+    pub max: Option<u64>,
+}
+
+impl ParamRange {
+    fn check(&self, name: &str, chain: &str, value: u64) -> Result<()> {
+        let below = self.min.is_some_and(|min| value < min);
+        let above = self.max.is_some_and(|max| value > max);
+        if below || above {
+            let bound = |bound: Option<u64>| bound.map_or("-".to_string(), |b| b.to_string());
+            return Err(eyre::eyre!(
+                "{} of {} is outside the {} to {} the relay accepts on {}",
+                name,
+                value,
+                bound(self.min),
+                bound(self.max),
+                chain
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Ranges the relay accepts for orders on one chain
+///
+/// Like the defaults, the timelock, fee and deadline ranges apply when the
+/// chain is the source and the confirmations range when it is the destination.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainParamLimits {
+    #[serde(default)]
+    pub timelock: ParamRange,
+    #[serde(default)]
+    pub fee: ParamRange,
+    #[serde(default)]
+    pub min_destination_confirmations: ParamRange,
+    #[serde(default)]
+    pub deadline_minutes: ParamRange,
+}
+
+/// Parameter ranges the relay accepts, by chain name
+///
+/// The API does not expose them, so they are read from a JSON file copied from
+/// the relay's configuration, e.g.
+/// `{"arbitrum_sepolia": {"timelock": {"min": 3600, "max": 50400}}}`.
+/// Chains missing from the file are not limited beyond what their HTLC enforces.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ParamLimits(pub BTreeMap<String, ChainParamLimits>);
+
+impl ParamLimits {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read parameter limits {}", path.display()))?;
+        serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Invalid parameter limits {}", path.display()))
+    }
+
+    pub fn for_chain(&self, chain: &str) -> ChainParamLimits {
+        self.0.get(chain).copied().unwrap_or_default()
+    }
+}
+
+impl OrderParams {
+    /// Defaults for a pair from config, with any overrides applied
+    pub fn for_pair(
+        source_chain: &str,
+        destination_chain: &str,
+        overrides: &OrderParamOverrides,
+    ) -> Self {
+        let source = chain_order_defaults(source_chain);
+        let destination = chain_order_defaults(destination_chain);
+        Self {
+            timelock: overrides.timelock.unwrap_or(source.timelock),
+            fee: overrides.fee.unwrap_or(source.fee),
+            min_destination_confirmations: overrides
+                .min_destination_confirmations
+                .unwrap_or(destination.min_destination_confirmations),
+            deadline_minutes: overrides
+                .deadline_minutes
+                .unwrap_or(source.deadline_minutes),
+        }
+    }
+
+    /// Check the parameters against the relay's ranges, the source HTLC's timelock
+    /// cap and each other, given the chains' block times
+    pub fn validate(
+        &self,
+        source_chain: &str,
+        destination_chain: &str,
+        limits: &ParamLimits,
+    ) -> Result<()> {
+        if self.timelock == 0 {
+            return Err(eyre::eyre!("Timelock must be at least one block"));
+        }
+        if self.min_destination_confirmations == 0 {
+            return Err(eyre::eyre!("Destination confirmations must be at least 1"));
+        }
+        if self.deadline_minutes == 0 {
+            return Err(eyre::eyre!("Deadline must be at least one minute"));
+        }
+
+        if let Some(max) = chain_order_defaults(source_chain).max_timelock {
+            if self.timelock > max {
+                return Err(eyre::eyre!(
+                    "Timelock of {} blocks exceeds the {} blocks the {} HTLC can enforce",
+                    self.timelock,
+                    max,
+                    source_chain
+                ));
+            }
+        }
+        let source = limits.for_chain(source_chain);
+        source
+            .timelock
+            .check("Timelock", source_chain, self.timelock)?;
+        source.fee.check("Fee", source_chain, self.fee)?;
+        source
+            .deadline_minutes
+            .check("Deadline", source_chain, self.deadline_minutes)?;
+        limits
+            .for_chain(destination_chain)
+            .min_destination_confirmations
+            .check(
+                "Destination confirmations",
+                destination_chain,
+                self.min_destination_confirmations,
+            )?;
+
+        // The source swap must stay locked until the order could have been initiated
+        // and the destination swap confirmed
+        let timelock_secs =
+            self.timelock as f64 * chain_order_defaults(source_chain).block_time_secs;
+        let confirmation_secs = self.min_destination_confirmations as f64
+            * chain_order_defaults(destination_chain).block_time_secs;
+        let required_secs = self.deadline_minutes as f64 * 60.0 + confirmation_secs;
+        if timelock_secs <= required_secs {
+            return Err(eyre::eyre!(
+                "Timelock of about {} on {} does not outlast the {} minute deadline plus {} of confirmations on {}",
+                format_duration(timelock_secs),
+                source_chain,
+                self.deadline_minutes,
+                format_duration(confirmation_secs),
+                destination_chain
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn format_duration(secs: f64) -> String {
+    if secs >= 3600.0 {
+        format!("{:.1} h", secs / 3600.0)
+    } else {
+        format!("{:.0} min", secs / 60.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timelock_must_outlast_deadline_and_confirmations() {
+        let none = ParamLimits::default();
+        let defaults =
+            OrderParams::for_pair("arbitrum_sepolia", "starknet_sepolia", &Default::default());
+        assert_eq!(defaults.timelock, 7300);
+        assert_eq!(defaults.min_destination_confirmations, 2);
+        assert!(
+            defaults
+                .validate("arbitrum_sepolia", "starknet_sepolia", &none)
+                .is_ok()
+        );
+
+        // 55 Ethereum-clocked blocks are 11 minutes, one short of the deadline plus
+        // two 30s Starknet confirmations
+        let overrides = OrderParamOverrides {
+            timelock: Some(55),
+            ..Default::default()
+        };
+        let short = OrderParams::for_pair("arbitrum_sepolia", "starknet_sepolia", &overrides);
+        let err = short
+            .validate("arbitrum_sepolia", "starknet_sepolia", &none)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("does not outlast the 10 minute deadline")
+        );
+        let longer = OrderParams {
+            timelock: 56,
+            ..short
+        };
+        assert!(
+            longer
+                .validate("arbitrum_sepolia", "starknet_sepolia", &none)
+                .is_ok()
+        );
+
+        let unconfirmed = OrderParams {
+            min_destination_confirmations: 0,
+            ..defaults
+        };
+        assert!(
+            unconfirmed
+                .validate("arbitrum_sepolia", "starknet_sepolia", &none)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parameters_stay_within_relay_and_htlc_limits() {
+        let limits: ParamLimits = serde_json::from_value(serde_json::json!({
+            "arbitrum_sepolia": { "timelock": { "min": 3600 }, "deadline_minutes": { "max": 30 } },
+            "starknet_sepolia": { "min_destination_confirmations": { "max": 10 } }
+        }))
+        .unwrap();
+        let params =
+            OrderParams::for_pair("arbitrum_sepolia", "starknet_sepolia", &Default::default());
+        assert!(
+            params
+                .validate("arbitrum_sepolia", "starknet_sepolia", &limits)
+                .is_ok()
+        );
+
+        let short = OrderParams {
+            timelock: 3599,
+            ..params
+        };
+        let err = short
+            .validate("arbitrum_sepolia", "starknet_sepolia", &limits)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Timelock of 3599 is outside the 3600 to -")
+        );
+        let slow = OrderParams {
+            min_destination_confirmations: 11,
+            ..params
+        };
+        assert!(
+            slow.validate("arbitrum_sepolia", "starknet_sepolia", &limits)
+                .is_err()
+        );
+        let late = OrderParams {
+            deadline_minutes: 31,
+            ..params
+        };
+        assert!(
+            late.validate("arbitrum_sepolia", "starknet_sepolia", &limits)
+                .is_err()
+        );
+
+        // Bitcoin's relative timelock cannot count past 0xFFFF blocks, with or without limits
+        let bitcoin =
+            OrderParams::for_pair("bitcoin_testnet", "arbitrum_sepolia", &Default::default());
+        let none = ParamLimits::default();
+        assert!(
+            bitcoin
+                .validate("bitcoin_testnet", "arbitrum_sepolia", &none)
+                .is_ok()
+        );
+        let too_long = OrderParams {
+            timelock: 0x1_0000,
+            ..bitcoin
+        };
+        assert!(
+            too_long
+                .validate("bitcoin_testnet", "arbitrum_sepolia", &none)
+                .is_err()
+        );
+    }
+}
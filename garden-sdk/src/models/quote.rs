@@ -0,0 +1,262 @@
+use alloy::sol;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuoteRequest {
+    pub order_pair: String,
+    pub amount: String,
+    pub exact_out: bool,
+}
+
+impl QuoteRequest {
+    /// Source and destination chain of the order pair
+    pub fn chains(&self) -> Option<(&str, &str)> {
+        let (source, destination) = self.order_pair.split_once("::")?;
+        Some((source.split(':').next()?, destination.split(':').next()?))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuoteResponse {
+    pub status: String,
+    pub result: QuoteResult,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuoteResult {
+    pub quotes: HashMap<String, String>,
+    pub input_token_price: f64,
+    pub output_token_price: f64,
+}
+
+/// Seconds a quote is treated as current after it was fetched, unless configured
+///
+/// Quote responses carry no expiry, so this is our own limit on how long a price
+/// is reused across orders, not one the API enforces. Prices are attested again
+/// when each order is created, see `QuoteService::with_validity`.
+pub const DEFAULT_QUOTE_VALIDITY_SECS: i64 = 30;
+
+/// Price quote for an order pair, with every strategy that answered
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Quote {
+    pub order_pair: String,
+    /// Source amount, or destination amount when `exact_out` is set
+    pub amount: String,
+    pub exact_out: bool,
+    /// Strategies ordered best first, never empty
+    pub strategies: Vec<StrategyQuote>,
+    pub input_token_price: f64,
+    pub output_token_price: f64,
+    /// Unix timestamp the quote was fetched at
+    pub fetched_at: i64,
+    /// Unix timestamp after which the quote should be refreshed
+    pub expires_at: i64,
+}
+
+impl Quote {
+    /// Strategy offering the largest destination amount, or for exact-out quotes
+    /// the smallest source amount
+    pub fn best(&self) -> &StrategyQuote {
+        &self.strategies[0]
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// What a single strategy offers for the quoted amount
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyQuote {
+    pub strategy_id: String,
+    pub source_amount: String,
+    pub destination_amount: String,
+}
+
+/// Prices and signature the attestation endpoint bound to an order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AttestedQuote {
+    pub strategy_id: String,
+    pub sig: String,
+    pub input_token_price: f64,
+    pub output_token_price: f64,
+    pub bitcoin_optional_recipient: Option<String>,
+}
+
+impl AttestedQuote {
+    /// How far the attested prices moved from the quote they were requested for
+    pub fn deviation_from(&self, quote: &Quote) -> PriceDeviation {
+        PriceDeviation {
+            input_bps: deviation_bps(quote.input_token_price, self.input_token_price),
+            output_bps: deviation_bps(quote.output_token_price, self.output_token_price),
+            destination_bps: deviation_bps(
+                price_ratio(quote.input_token_price, quote.output_token_price),
+                price_ratio(self.input_token_price, self.output_token_price),
+            ),
+        }
+    }
+}
+
+/// Absolute price moves between a quote and its attestation, in basis points
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct PriceDeviation {
+    pub input_bps: f64,
+    pub output_bps: f64,
+    /// Move of the destination amount implied by the input/output price ratio
+    pub destination_bps: f64,
+}
+
+impl PriceDeviation {
+    pub fn max_bps(&self) -> f64 {
+        self.input_bps
+            .max(self.output_bps)
+            .max(self.destination_bps)
+    }
+}
+
+// Destination units received per source unit at the given prices
+fn price_ratio(input_price: f64, output_price: f64) -> f64 {
+    if output_price == 0.0 {
+        0.0
+    } else {
+        input_price / output_price
+    }
+}
+
+fn deviation_bps(from: f64, to: f64) -> f64 {
+    if from == 0.0 {
+        if to == 0.0 { 0.0 } else { f64::INFINITY }
+    } else {
+        ((to - from) / from).abs() * 10_000.0
+    }
+}
+
+/// Which leg of an order a relay action runs on
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PerformOn {
+    Source,
+    Destination,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RedeemRequest {
+    /// Unique identifier of the order
+    pub order_id: String,
+    /// Secret used to redeem the order
+    pub secret: String,
+    /// Leg to redeem; the user always redeems the solver's destination swap
+    pub perform_on: PerformOn,
+}
+
+/// How a redeem transaction was submitted
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum RedeemRoute {
+    /// Posted to the relayer of the destination chain
+    Relayer,
+    /// Sent directly to the destination HTLC after the relayer failed
+    OnChain,
+}
+
+/// A submitted redeem
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RedeemResult {
+    pub order_id: String,
+    /// Destination chain the redeem ran on
+    pub chain: String,
+    pub tx_hash: String,
+    pub route: RedeemRoute,
+}
+
+impl fmt::Display for RedeemResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let route = match self.route {
+            RedeemRoute::Relayer => "relayer",
+            RedeemRoute::OnChain => "direct transaction",
+        };
+        write!(f, "{} on {} via {}", self.tx_hash, self.chain, route)
+    }
+}
+
+sol! {
+    struct Initiate {
+        address redeemer;
+        uint256 timelock;
+        uint256 amount;
+        bytes32 secretHash;
+    }
+}
+
+// Keep the original Initiate struct but rename it to avoid conflicts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InitiateRequest {
+    /// Unique identifier of the order
+    pub order_id: String,
+    /// EIP-712 signature for the initiate request
+    pub signature: String,
+    /// Leg to initiate; the user always initiates the source swap
+    pub perform_on: PerformOn,
+}
+
+// Source swap fields covered by the initiate signature
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InitiateParams {
+    /// Chain the order is initiated on, e.g. `arbitrum_sepolia` or `starknet_sepolia`
+    pub source_chain: String,
+    /// Expected signer: EVM address or Starknet account address
+    pub initiator: String,
+    /// Redeemer of the source swap
+    pub redeemer: String,
+    /// Source swap amount in base units
+    pub amount: String,
+    /// Source swap timelock in blocks
+    pub timelock: u64,
+    /// Hex encoded secret hash of the order
+    pub secret_hash: String,
+}
+
+// Everything needed to check an initiate signature without talking to the relay
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InitiateSignaturePayload {
+    /// Order the signature belongs to, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(flatten)]
+    pub params: InitiateParams,
+    /// Signature sent in the initiate request
+    pub signature: String,
+    /// Starknet account public key, required for Starknet sources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+}
+
+// Initiate payloads prepared online and carried to an offline machine for signing
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InitiateBundle {
+    /// Unix timestamp the bundle was prepared at
+    pub prepared_at: i64,
+    pub entries: Vec<InitiateBundleEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InitiateBundleEntry {
+    /// Unique identifier of the order
+    pub order_id: String,
+    #[serde(flatten)]
+    pub params: InitiateParams,
+    /// Signature over `params`, filled in by the sign step
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl InitiateBundleEntry {
+    // Initiate request for a signed entry
+    pub fn initiate_request(&self) -> Option<InitiateRequest> {
+        self.signature.as_ref().map(|signature| InitiateRequest {
+            order_id: self.order_id.clone(),
+            signature: signature.clone(),
+            perform_on: PerformOn::Source,
+        })
+    }
+}
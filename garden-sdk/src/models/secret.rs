@@ -0,0 +1,119 @@
+use std::fmt;
+
+use alloy::primitives::{B256, U256};
+use eyre::{Result, WrapErr};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+/// SHA-256 hash of an order secret
+///
+/// Both legs of a swap lock on the same hash, so every chain hashes with
+/// SHA-256; chains only differ in how the 32 bytes are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SecretHash([u8; 32]);
+
+impl SecretHash {
+    /// Hash of a secret
+    pub fn of(secret: &[u8]) -> Self {
+        Self(Sha256::digest(secret).into())
+    }
+
+    /// Parse a hex hash, with or without `0x`
+    pub fn from_hex(hash: &str) -> Result<Self> {
+        let hash = hash.trim();
+        let bytes = hex::decode(hash.strip_prefix("0x").unwrap_or(hash))
+            .wrap_err_with(|| format!("Invalid secret hash: {}", hash))?;
+        let bytes: [u8; 32] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            eyre::eyre!("Secret hash must be 32 bytes, got {}", bytes.len())
+        })?;
+        Ok(Self(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// `bytes32` as the EVM HTLC takes it
+    pub fn to_bytes32(&self) -> B256 {
+        B256::from(self.0)
+    }
+
+    /// Eight big-endian `u32` words, the layout of Cairo's SHA-256 output that the
+    /// Starknet HTLC takes as `secret_hash`
+    pub fn to_u32_words(&self) -> [u32; 8] {
+        u32_words(&self.0)
+    }
+
+    /// `(low, high)` 128-bit limbs of the hash read as a big-endian Cairo `u256`
+    pub fn to_u256_limbs(&self) -> (u128, u128) {
+        u256_limbs(U256::from_be_bytes(self.0))
+    }
+}
+
+impl fmt::Display for SecretHash {
+    // Plain lowercase hex, as the orderbook stores it
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+/// How an HTLC chain expects the secret hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretScheme {
+    /// `bytes32`, 0x-prefixed hex
+    Evm,
+    /// `u32` words sent as the `u128*` array of the SNIP-12 `Initiate` message
+    Starknet,
+    /// Raw SHA-256 digest as used by `OP_SHA256`, unprefixed hex
+    Bitcoin,
+}
+
+impl SecretScheme {
+    /// Scheme of the HTLC on a chain, e.g. `starknet_sepolia` or `arbitrum_sepolia`
+    pub fn for_chain(chain: &str) -> Self {
+        if chain.starts_with("starknet") {
+            Self::Starknet
+        } else if chain.starts_with("bitcoin") {
+            Self::Bitcoin
+        } else {
+            Self::Evm
+        }
+    }
+
+    /// Hash a secret for this chain
+    pub fn hash(&self, secret: &[u8]) -> SecretHash {
+        SecretHash::of(secret)
+    }
+
+    /// The hash as this chain's typed data or payloads carry it
+    pub fn encode(&self, hash: &SecretHash) -> Value {
+        match self {
+            Self::Evm => json!(hash.to_bytes32().to_string()),
+            Self::Starknet => Value::Array(
+                hash.to_u32_words()
+                    .iter()
+                    .map(|word| json!(word.to_string()))
+                    .collect(),
+            ),
+            Self::Bitcoin => json!(hash.to_string()),
+        }
+    }
+}
+
+/// 32 bytes as eight big-endian `u32` words, the `Array<u32>` layout Cairo uses for
+/// SHA-256 digests and preimages
+pub fn u32_words(bytes: &[u8; 32]) -> [u32; 8] {
+    let mut words = [0u32; 8];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// `(low, high)` 128-bit limbs of a `u256`
+pub fn u256_limbs(value: U256) -> (u128, u128) {
+    let mask = U256::from(u128::MAX);
+    let low = (value & mask).to::<u128>();
+    let high = (value >> 128usize).to::<u128>();
+    (low, high)
+}
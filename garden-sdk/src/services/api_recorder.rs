@@ -0,0 +1,357 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::models::matched_order::{MatchedOrder, OrderDetails};
+use crate::models::order::{ApiResponse, AttestedResponse, Order, Paginated};
+use crate::models::quote::{InitiateRequest, QuoteRequest, QuoteResponse, RedeemRequest};
+use crate::services::garden_api::{ApiFuture, GardenApi};
+use eyre::{Result, WrapErr};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tracing::warn;
+
+/// Placeholder written in place of redacted values
+pub const REDACTED: &str = "<redacted>";
+
+// Fields whose values never leave the process when recording
+const SENSITIVE_FIELDS: &[&str] = &[
+    "secret",
+    "api_key",
+    "api-key",
+    "private_key",
+    "guardian_key",
+];
+
+/// One request/response pair seen by a [`GardenApi`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exchange {
+    /// Trait method that handled the call, e.g. `create_order`
+    pub endpoint: String,
+    pub request: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+    /// Error message when the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// [`GardenApi`] wrapper that keeps every exchange made through it
+///
+/// Secrets and keys are redacted before an exchange is stored. Only bodies
+/// are recorded, so the `api-key` header never reaches a fixture.
+#[derive(Debug, Clone)]
+pub struct RecordingGardenApi {
+    inner: Arc<dyn GardenApi>,
+    exchanges: Arc<Mutex<Vec<Exchange>>>,
+    fixture_dir: Option<PathBuf>,
+}
+
+impl RecordingGardenApi {
+    pub fn new(inner: Arc<dyn GardenApi>) -> Self {
+        Self {
+            inner,
+            exchanges: Arc::new(Mutex::new(Vec::new())),
+            fixture_dir: None,
+        }
+    }
+
+    /// Also write each exchange to `<dir>/<seq>-<endpoint>.json` as it happens
+    pub fn with_fixture_dir(mut self, dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        self.fixture_dir = Some(dir);
+        Ok(self)
+    }
+
+    /// Exchanges recorded so far, oldest first
+    pub fn exchanges(&self) -> Vec<Exchange> {
+        self.exchanges.lock().unwrap().clone()
+    }
+
+    // Store the outcome of a call and hand it back unchanged
+    //
+    // Recording is best-effort: a call that went through, like a created order,
+    // must reach the caller even when its exchange cannot be stored
+    fn record<R: Serialize, T: Serialize>(
+        &self,
+        endpoint: &str,
+        request: &R,
+        result: Result<T>,
+    ) -> Result<T> {
+        if let Err(e) = self.try_record(endpoint, request, &result) {
+            warn!("⚠️ Failed to record {} exchange: {:#}", endpoint, e);
+        }
+        result
+    }
+
+    fn try_record<R: Serialize, T: Serialize>(
+        &self,
+        endpoint: &str,
+        request: &R,
+        result: &Result<T>,
+    ) -> Result<()> {
+        let request = serde_json::to_value(request)?;
+        let (response, error) = match result {
+            Ok(response) => (Some(serde_json::to_value(response)?), None),
+            Err(e) => (None, Some(redact_message(&e.to_string(), &request))),
+        };
+        let mut exchange = Exchange {
+            endpoint: endpoint.to_string(),
+            request,
+            response,
+            error,
+        };
+        redact(&mut exchange.request);
+        if let Some(response) = exchange.response.as_mut() {
+            redact(response);
+        }
+
+        let mut exchanges = self.exchanges.lock().unwrap();
+        let seq = exchanges.len() + 1;
+        exchanges.push(exchange.clone());
+        drop(exchanges);
+        if let Some(dir) = &self.fixture_dir {
+            let path = dir.join(format!("{:04}-{}.json", seq, endpoint));
+            fs::write(&path, serde_json::to_string_pretty(&exchange)?)
+                .wrap_err_with(|| format!("Failed to write fixture {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+impl GardenApi for RecordingGardenApi {
+    fn quote<'a>(&'a self, request: &'a QuoteRequest) -> ApiFuture<'a, QuoteResponse> {
+        Box::pin(async move {
+            let result = self.inner.quote(request).await;
+            self.record("quote", request, result)
+        })
+    }
+
+    fn attested_quote<'a>(&'a self, payload: &'a Value) -> ApiFuture<'a, AttestedResponse> {
+        Box::pin(async move {
+            let result = self.inner.attested_quote(payload).await;
+            self.record("attested_quote", payload, result)
+        })
+    }
+
+    fn create_order<'a>(&'a self, order: &'a Order) -> ApiFuture<'a, ApiResponse<String>> {
+        Box::pin(async move {
+            let result = self.inner.create_order(order).await;
+            self.record("create_order", order, result)
+        })
+    }
+
+    fn initiate<'a>(&'a self, request: &'a InitiateRequest) -> ApiFuture<'a, ApiResponse<String>> {
+        Box::pin(async move {
+            let result = self.inner.initiate(request).await;
+            self.record("initiate", request, result)
+        })
+    }
+
+    fn matched_order<'a>(&'a self, order_id: &'a str) -> ApiFuture<'a, OrderDetails> {
+        Box::pin(async move {
+            let result = self.inner.matched_order(order_id).await;
+            self.record("matched_order", &order_id, result)
+        })
+    }
+
+    fn user_orders<'a>(
+        &'a self,
+        address: &'a str,
+        page: u32,
+        per_page: u32,
+    ) -> ApiFuture<'a, ApiResponse<Paginated<MatchedOrder>>> {
+        Box::pin(async move {
+            let result = self.inner.user_orders(address, page, per_page).await;
+            let request = json!({ "address": address, "page": page, "per_page": per_page });
+            self.record("user_orders", &request, result)
+        })
+    }
+
+    fn redeem<'a>(&'a self, chain: &'a str, request: &'a RedeemRequest) -> ApiFuture<'a, String> {
+        Box::pin(async move {
+            let result = self.inner.redeem(chain, request).await;
+            self.record("redeem", request, result)
+        })
+    }
+}
+
+/// [`GardenApi`] that answers from previously recorded exchanges
+///
+/// Responses are served per endpoint in recording order. Requests are not
+/// matched, since orders carry fresh nonces and secret hashes on every run.
+#[derive(Debug)]
+pub struct ReplayGardenApi {
+    exchanges: Mutex<HashMap<String, VecDeque<Exchange>>>,
+}
+
+impl ReplayGardenApi {
+    pub fn new(exchanges: Vec<Exchange>) -> Self {
+        let mut by_endpoint: HashMap<String, VecDeque<Exchange>> = HashMap::new();
+        for exchange in exchanges {
+            by_endpoint
+                .entry(exchange.endpoint.clone())
+                .or_default()
+                .push_back(exchange);
+        }
+        Self {
+            exchanges: Mutex::new(by_endpoint),
+        }
+    }
+
+    /// Replay the fixtures a [`RecordingGardenApi`] wrote to `dir`
+    pub fn from_fixture_dir(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref();
+        let mut paths = fs::read_dir(dir)
+            .map_err(|e| eyre::eyre!("Failed to read fixture dir {}: {}", dir.display(), e))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+        paths.sort();
+
+        let mut exchanges = Vec::with_capacity(paths.len());
+        for path in paths {
+            let content = fs::read_to_string(&path)?;
+            let exchange = serde_json::from_str(&content)
+                .map_err(|e| eyre::eyre!("Invalid fixture {}: {}", path.display(), e))?;
+            exchanges.push(exchange);
+        }
+        Ok(Self::new(exchanges))
+    }
+
+    // Pop the next recorded response for an endpoint
+    fn next<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let exchange = self
+            .exchanges
+            .lock()
+            .unwrap()
+            .get_mut(endpoint)
+            .and_then(|queue| queue.pop_front())
+            .ok_or_else(|| eyre::eyre!("No recorded {} exchange left to replay", endpoint))?;
+
+        if let Some(error) = exchange.error {
+            return Err(eyre::eyre!(error));
+        }
+        let response = exchange
+            .response
+            .ok_or_else(|| eyre::eyre!("Recorded {} exchange has no response", endpoint))?;
+        Ok(serde_json::from_value(response)?)
+    }
+}
+
+impl GardenApi for ReplayGardenApi {
+    fn quote<'a>(&'a self, _request: &'a QuoteRequest) -> ApiFuture<'a, QuoteResponse> {
+        Box::pin(async move { self.next("quote") })
+    }
+
+    fn attested_quote<'a>(&'a self, _payload: &'a Value) -> ApiFuture<'a, AttestedResponse> {
+        Box::pin(async move { self.next("attested_quote") })
+    }
+
+    fn create_order<'a>(&'a self, _order: &'a Order) -> ApiFuture<'a, ApiResponse<String>> {
+        Box::pin(async move { self.next("create_order") })
+    }
+
+    fn initiate<'a>(&'a self, _request: &'a InitiateRequest) -> ApiFuture<'a, ApiResponse<String>> {
+        Box::pin(async move { self.next("initiate") })
+    }
+
+    fn matched_order<'a>(&'a self, _order_id: &'a str) -> ApiFuture<'a, OrderDetails> {
+        Box::pin(async move { self.next("matched_order") })
+    }
+
+    fn user_orders<'a>(
+        &'a self,
+        _address: &'a str,
+        _page: u32,
+        _per_page: u32,
+    ) -> ApiFuture<'a, ApiResponse<Paginated<MatchedOrder>>> {
+        Box::pin(async move { self.next("user_orders") })
+    }
+
+    fn redeem<'a>(&'a self, _chain: &'a str, _request: &'a RedeemRequest) -> ApiFuture<'a, String> {
+        Box::pin(async move { self.next("redeem") })
+    }
+}
+
+/// Replace secrets and keys in a recorded body with [`REDACTED`]
+///
+/// String values holding JSON, such as raw relayer responses, are redacted too.
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&key.as_str()) && !is_blank(field) {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        Value::String(text) => {
+            if let Ok(mut inner @ (Value::Object(_) | Value::Array(_))) =
+                serde_json::from_str::<Value>(text)
+            {
+                redact(&mut inner);
+                *text = inner.to_string();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Redact an error message the way [`redact`] does a body
+///
+/// Sensitive values sent in `request` are replaced wherever the message echoes
+/// them, and a JSON body at the end of the message is redacted field by field.
+pub fn redact_message(message: &str, request: &Value) -> String {
+    let mut sensitive = Vec::new();
+    sensitive_values(request, &mut sensitive);
+    let mut message = sensitive
+        .iter()
+        .fold(message.to_string(), |message, value| {
+            message.replace(value.as_str(), REDACTED)
+        });
+
+    if let Some(start) = message.find(['{', '[']) {
+        if let Ok(mut body) = serde_json::from_str::<Value>(&message[start..]) {
+            redact(&mut body);
+            message = format!("{}{}", &message[..start], body);
+        }
+    }
+    message
+}
+
+// Non-blank string values of sensitive fields anywhere in a body
+fn sensitive_values(value: &Value, found: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map {
+                match field {
+                    Value::String(text)
+                        if SENSITIVE_FIELDS.contains(&key.as_str()) && !text.is_empty() =>
+                    {
+                        found.push(text.clone())
+                    }
+                    _ => sensitive_values(field, found),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| sensitive_values(item, found)),
+        _ => {}
+    }
+}
+
+// Nothing worth hiding in null or empty values, and keeping them preserves
+// the "not revealed yet" signal in recorded swaps
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::config::settings::TESTNET_REDEEM_RELAYERS;
+use crate::models::matched_order::{MatchedOrder, OrderDetails};
+use crate::models::order::{ApiResponse, AttestedResponse, Order, Paginated};
+use crate::models::quote::{InitiateRequest, QuoteRequest, QuoteResponse, RedeemRequest};
+use eyre::Result;
+use reqwest::{Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::{info, warn};
+
+/// Boxed future returned by [`GardenApi`] calls, so the trait stays object safe
+pub type ApiFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// The Garden endpoints `OrderService` talks to
+///
+/// Implementations only move requests and responses; interpreting API level
+/// error statuses is left to the service.
+pub trait GardenApi: Debug + Send + Sync {
+    /// `GET /quote`
+    fn quote<'a>(&'a self, request: &'a QuoteRequest) -> ApiFuture<'a, QuoteResponse>;
+
+    /// `POST /quote/attested` with an attestation payload such as
+    /// [`Order::attestation_payload`]
+    fn attested_quote<'a>(&'a self, payload: &'a Value) -> ApiFuture<'a, AttestedResponse>;
+
+    /// `POST /create-order`
+    fn create_order<'a>(&'a self, order: &'a Order) -> ApiFuture<'a, ApiResponse<String>>;
+
+    /// `POST /initiate`
+    fn initiate<'a>(&'a self, request: &'a InitiateRequest) -> ApiFuture<'a, ApiResponse<String>>;
+
+    /// `GET /orders/id/matched/{order_id}`
+    fn matched_order<'a>(&'a self, order_id: &'a str) -> ApiFuture<'a, OrderDetails>;
+
+    /// `GET /orders/user/matched/{address}`, one page of the orders an address initiated
+    fn user_orders<'a>(
+        &'a self,
+        address: &'a str,
+        page: u32,
+        per_page: u32,
+    ) -> ApiFuture<'a, ApiResponse<Paginated<MatchedOrder>>>;
+
+    /// `POST /redeem` on the relayer for the order's destination chain, returning the
+    /// raw response body
+    fn redeem<'a>(&'a self, chain: &'a str, request: &'a RedeemRequest) -> ApiFuture<'a, String>;
+}
+
+/// [`GardenApi`] over HTTP with reqwest
+#[derive(Debug, Clone)]
+pub struct ReqwestGardenApi {
+    client: Client,
+    relay_url: String,
+    orderbook_url: String,
+    relayer_urls: BTreeMap<String, String>,
+    api_key: String,
+}
+
+impl Default for ReqwestGardenApi {
+    fn default() -> Self {
+        Self::testnet()
+    }
+}
+
+impl ReqwestGardenApi {
+    /// Client for the given relay and orderbook/quote base URLs
+    ///
+    /// Redeems are only sent to chains given a relayer with [`Self::with_relayer`].
+    pub fn new(
+        relay_url: impl Into<String>,
+        orderbook_url: impl Into<String>,
+        api_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            relay_url: relay_url.into(),
+            orderbook_url: orderbook_url.into(),
+            relayer_urls: BTreeMap::new(),
+            api_key: api_key.into(),
+        }
+    }
+
+    /// Redeem orders arriving on `chain` through this relayer
+    pub fn with_relayer(mut self, chain: impl Into<String>, url: impl Into<String>) -> Self {
+        self.relayer_urls.insert(chain.into(), url.into());
+        self
+    }
+
+    /// Base URL redeems on a destination chain are posted to
+    pub fn relayer_url(&self, chain: &str) -> Result<&str> {
+        self.relayer_urls
+            .get(chain)
+            .map(String::as_str)
+            .ok_or_else(|| eyre::eyre!("No redeem relayer configured for chain: {}", chain))
+    }
+
+    /// Client for the Garden testnet deployment
+    pub fn testnet() -> Self {
+        let api = Self::new(
+            "https://evm-relay-stage.hashira.io",
+            "https://testnet.api.hashira.io",
+            "AAAAAGnnHDw6OuFGHwJsgTQFutrJ4__itFiulz-_iRsD-c-OBaOhqZ4KWhB98QhtVYybp2UFWcv8O2LIoB7EmL77dkeQZ_4isbYc",
+        );
+        TESTNET_REDEEM_RELAYERS.iter().fold(api, |api, relayer| {
+            relayer
+                .chains
+                .iter()
+                .fold(api, |api, chain| api.with_relayer(*chain, relayer.url))
+        })
+    }
+
+    // Send a request and return the body of a successful response
+    async fn send_text(&self, request: RequestBuilder, action: &str) -> Result<String> {
+        let response = request
+            .header("accept", "application/json")
+            .header("api-key", &self.api_key)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await?;
+        info!("📥 Received {} response: Status {}", action, status);
+
+        if !status.is_success() {
+            warn!("❌ Failed to {}: {} - {}", action, status, text);
+            return Err(eyre::eyre!("Failed to {}: {} - {}", action, status, text));
+        }
+        Ok(text)
+    }
+
+    // Send a request and decode the JSON body of a successful response
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        action: &str,
+    ) -> Result<T> {
+        let text = self.send_text(request, action).await?;
+        if text.trim().is_empty() {
+            warn!("❌ Empty response body received");
+            return Err(eyre::eyre!("Empty response body"));
+        }
+
+        serde_json::from_str(&text).map_err(|e| {
+            warn!("❌ Failed to decode response body: {}", e);
+            eyre::eyre!("Failed to decode response body: {}. Raw body: {}", e, text)
+        })
+    }
+}
+
+impl GardenApi for ReqwestGardenApi {
+    fn quote<'a>(&'a self, request: &'a QuoteRequest) -> ApiFuture<'a, QuoteResponse> {
+        Box::pin(async move {
+            let url = format!(
+                "{}/quote?order_pair={}&amount={}&exact_out={}",
+                self.orderbook_url, request.order_pair, request.amount, request.exact_out
+            );
+            self.send_json(self.client.get(url), "get quote").await
+        })
+    }
+
+    fn attested_quote<'a>(&'a self, payload: &'a Value) -> ApiFuture<'a, AttestedResponse> {
+        Box::pin(async move {
+            let url = format!("{}/quote/attested", self.orderbook_url);
+            self.send_json(self.client.post(url).json(payload), "get attested quote")
+                .await
+        })
+    }
+
+    fn create_order<'a>(&'a self, order: &'a Order) -> ApiFuture<'a, ApiResponse<String>> {
+        Box::pin(async move {
+            let url = format!("{}/create-order", self.relay_url);
+            self.send_json(self.client.post(url).json(order), "create order")
+                .await
+        })
+    }
+
+    fn initiate<'a>(&'a self, request: &'a InitiateRequest) -> ApiFuture<'a, ApiResponse<String>> {
+        Box::pin(async move {
+            let url = format!("{}/initiate", self.relay_url);
+            self.send_json(self.client.post(url).json(request), "initiate order")
+                .await
+        })
+    }
+
+    fn matched_order<'a>(&'a self, order_id: &'a str) -> ApiFuture<'a, OrderDetails> {
+        Box::pin(async move {
+            let url = format!("{}/orders/id/matched/{}", self.orderbook_url, order_id);
+            self.send_json(self.client.get(url), "get order details")
+                .await
+        })
+    }
+
+    fn user_orders<'a>(
+        &'a self,
+        address: &'a str,
+        page: u32,
+        per_page: u32,
+    ) -> ApiFuture<'a, ApiResponse<Paginated<MatchedOrder>>> {
+        Box::pin(async move {
+            let url = format!(
+                "{}/orders/user/matched/{}?page={}&per_page={}",
+                self.orderbook_url, address, page, per_page
+            );
+            self.send_json(self.client.get(url), "get user orders")
+                .await
+        })
+    }
+
+    fn redeem<'a>(&'a self, chain: &'a str, request: &'a RedeemRequest) -> ApiFuture<'a, String> {
+        Box::pin(async move {
+            let url = format!("{}/redeem", self.relayer_url(chain)?);
+            self.send_text(self.client.post(url).json(request), "redeem order")
+                .await
+        })
+    }
+}
@@ -0,0 +1,303 @@
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::settings::asset_decimals;
+use crate::models::address::ChainAddress;
+use crate::models::matched_order::SwapData;
+use crate::services::order_history::HistoryEntry;
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use eyre::{Result, WrapErr};
+use parquet::arrow::ArrowWriter;
+use serde::Serialize;
+
+/// Ledger columns in file order
+///
+/// Downstream spreadsheets address columns by name and position, so new
+/// columns are only ever appended and existing ones never renamed or dropped.
+pub const LEDGER_COLUMNS: &[&str] = &[
+    "order_id",
+    "created_at",
+    "source_chain",
+    "source_asset",
+    "destination_chain",
+    "destination_asset",
+    "source_amount",
+    "destination_amount",
+    "fee",
+    "input_token_price",
+    "output_token_price",
+    "source_value_usd",
+    "destination_value_usd",
+    "strategy_id",
+    "status",
+    "source_initiate_tx_hash",
+    "source_redeem_tx_hash",
+    "source_refund_tx_hash",
+    "destination_initiate_tx_hash",
+    "destination_redeem_tx_hash",
+    "destination_refund_tx_hash",
+    "refreshed_at",
+];
+
+/// One order in the ledger, fields in [`LEDGER_COLUMNS`] order
+///
+/// Amounts and the fee stay in the asset's smallest unit as exact decimal
+/// strings. USD values are only filled for assets listed in `ASSET_DECIMALS`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LedgerRow {
+    pub order_id: String,
+    /// RFC 3339, UTC
+    pub created_at: String,
+    pub source_chain: String,
+    pub source_asset: String,
+    pub destination_chain: String,
+    pub destination_asset: String,
+    pub source_amount: String,
+    pub destination_amount: String,
+    pub fee: String,
+    pub input_token_price: Option<f64>,
+    pub output_token_price: Option<f64>,
+    pub source_value_usd: Option<f64>,
+    pub destination_value_usd: Option<f64>,
+    pub strategy_id: String,
+    /// `OrderStatus` variant name
+    pub status: String,
+    pub source_initiate_tx_hash: Option<String>,
+    pub source_redeem_tx_hash: Option<String>,
+    pub source_refund_tx_hash: Option<String>,
+    pub destination_initiate_tx_hash: Option<String>,
+    pub destination_redeem_tx_hash: Option<String>,
+    pub destination_refund_tx_hash: Option<String>,
+    /// RFC 3339, UTC
+    pub refreshed_at: String,
+}
+
+impl LedgerRow {
+    pub fn from_entry(order_id: &str, entry: &HistoryEntry) -> Self {
+        let order = &entry.order.create_order;
+        let prices = &order.additional_data;
+        let source = &entry.order.source_swap;
+        let destination = &entry.order.destination_swap;
+        Self {
+            order_id: order_id.to_string(),
+            created_at: rfc3339(entry.created_at()),
+            source_chain: order.source_chain.clone(),
+            source_asset: order.source_asset.to_string(),
+            destination_chain: order.destination_chain.clone(),
+            destination_asset: order.destination_asset.to_string(),
+            source_amount: order.source_amount.normalized().to_plain_string(),
+            destination_amount: order.destination_amount.normalized().to_plain_string(),
+            fee: order.fee.normalized().to_plain_string(),
+            input_token_price: prices.input_token_price,
+            output_token_price: prices.output_token_price,
+            source_value_usd: usd_value(
+                &order.source_chain,
+                &order.source_asset,
+                &order.source_amount,
+                prices.input_token_price,
+            ),
+            destination_value_usd: usd_value(
+                &order.destination_chain,
+                &order.destination_asset,
+                &order.destination_amount,
+                prices.output_token_price,
+            ),
+            strategy_id: prices.strategy_id.clone(),
+            status: format!("{:?}", entry.status()),
+            source_initiate_tx_hash: tx(source, |swap| &swap.initiate_tx_hash),
+            source_redeem_tx_hash: tx(source, |swap| &swap.redeem_tx_hash),
+            source_refund_tx_hash: tx(source, |swap| &swap.refund_tx_hash),
+            destination_initiate_tx_hash: tx(destination, |swap| &swap.initiate_tx_hash),
+            destination_redeem_tx_hash: tx(destination, |swap| &swap.redeem_tx_hash),
+            destination_refund_tx_hash: tx(destination, |swap| &swap.refund_tx_hash),
+            refreshed_at: rfc3339(entry.refreshed_at),
+        }
+    }
+}
+
+fn rfc3339(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+// Smallest-unit amount in whole tokens times the token's USD price
+fn usd_value(
+    chain: &str,
+    asset: &ChainAddress,
+    amount: &BigDecimal,
+    price: Option<f64>,
+) -> Option<f64> {
+    let decimals = asset_decimals(chain, asset)?;
+    let tokens = (amount / BigDecimal::from(10u64.pow(decimals))).to_f64()?;
+    Some(tokens * price?)
+}
+
+// The API leaves "0x" in place of transactions that did not happen
+fn tx(swap: &SwapData, hash: impl Fn(&SwapData) -> &Option<String>) -> Option<String> {
+    hash(swap).clone().filter(|hash| hash != "0x")
+}
+
+/// File format of a ledger export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerFormat {
+    Csv,
+    Parquet,
+}
+
+impl LedgerFormat {
+    /// Format named by a path's extension, `.csv` or `.parquet`
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Ok(Self::Csv),
+            Some(ext) if ext.eq_ignore_ascii_case("parquet") => Ok(Self::Parquet),
+            _ => Err(eyre::eyre!(
+                "Cannot tell the export format of {}, use a .csv or .parquet file",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// Write ledger rows to `path` in the given format, replacing the file
+pub fn write_ledger(
+    path: impl AsRef<Path>,
+    format: LedgerFormat,
+    rows: &[LedgerRow],
+) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    match format {
+        LedgerFormat::Csv => write_csv(path, rows),
+        LedgerFormat::Parquet => write_parquet(path, rows),
+    }
+    .wrap_err_with(|| format!("Failed to export ledger to {}", path.display()))
+}
+
+fn write_csv(path: &Path, rows: &[LedgerRow]) -> Result<()> {
+    // The header is written explicitly so an empty ledger still has its columns
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_path(path)?;
+    writer.write_record(LEDGER_COLUMNS)?;
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_parquet(path: &Path, rows: &[LedgerRow]) -> Result<()> {
+    let strings = |value: fn(&LedgerRow) -> Option<&str>| -> ArrayRef {
+        Arc::new(rows.iter().map(value).collect::<StringArray>())
+    };
+    let floats = |value: fn(&LedgerRow) -> Option<f64>| -> ArrayRef {
+        Arc::new(rows.iter().map(value).collect::<Float64Array>())
+    };
+    let columns: Vec<ArrayRef> = vec![
+        strings(|row| Some(&row.order_id)),
+        strings(|row| Some(&row.created_at)),
+        strings(|row| Some(&row.source_chain)),
+        strings(|row| Some(&row.source_asset)),
+        strings(|row| Some(&row.destination_chain)),
+        strings(|row| Some(&row.destination_asset)),
+        strings(|row| Some(&row.source_amount)),
+        strings(|row| Some(&row.destination_amount)),
+        strings(|row| Some(&row.fee)),
+        floats(|row| row.input_token_price),
+        floats(|row| row.output_token_price),
+        floats(|row| row.source_value_usd),
+        floats(|row| row.destination_value_usd),
+        strings(|row| Some(&row.strategy_id)),
+        strings(|row| Some(&row.status)),
+        strings(|row| row.source_initiate_tx_hash.as_deref()),
+        strings(|row| row.source_redeem_tx_hash.as_deref()),
+        strings(|row| row.source_refund_tx_hash.as_deref()),
+        strings(|row| row.destination_initiate_tx_hash.as_deref()),
+        strings(|row| row.destination_redeem_tx_hash.as_deref()),
+        strings(|row| row.destination_refund_tx_hash.as_deref()),
+        strings(|row| Some(&row.refreshed_at)),
+    ];
+    let schema = Schema::new(
+        LEDGER_COLUMNS
+            .iter()
+            .zip(&columns)
+            .map(|(name, column)| {
+                let nullable =
+                    column.data_type() == &DataType::Float64 || name.ends_with("tx_hash");
+                Field::new(*name, column.data_type().clone(), nullable)
+            })
+            .collect::<Vec<_>>(),
+    );
+    let batch = RecordBatch::try_new(Arc::new(schema), columns)?;
+
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::order_history::OrderHistory;
+    use crate::test_support::{ORDER_ID, matched_order_fixture};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    #[test]
+    fn exports_the_ledger_to_csv_and_parquet() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history = OrderHistory::open(dir.path().join("order_history.json")).unwrap();
+        history.insert(ORDER_ID, matched_order_fixture(), 1_760_870_400);
+        let row = LedgerRow::from_entry(ORDER_ID, history.get(ORDER_ID).unwrap());
+
+        assert_eq!(row.created_at, "2025-10-19T10:40:00Z");
+        assert_eq!(row.source_amount, "10000");
+        assert_eq!(row.status, "CounterpartyInitiated");
+        // 10000 sats of 8-decimal testnet WBTC at $1; the Starknet asset has no known decimals
+        assert_eq!(row.source_value_usd, Some(0.0001));
+        assert_eq!(row.destination_value_usd, None);
+        assert!(row.source_initiate_tx_hash.is_some());
+        assert_eq!(row.destination_redeem_tx_hash, None);
+
+        let csv_path = dir.path().join("ledger.csv");
+        let format = LedgerFormat::from_path(&csv_path).unwrap();
+        write_ledger(&csv_path, format, std::slice::from_ref(&row)).unwrap();
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], LEDGER_COLUMNS.join(","));
+        assert!(lines[1].starts_with(&format!(
+            "{},2025-10-19T10:40:00Z,arbitrum_sepolia,",
+            ORDER_ID
+        )));
+
+        let parquet_path = dir.path().join("ledger.parquet");
+        let format = LedgerFormat::from_path(&parquet_path).unwrap();
+        write_ledger(&parquet_path, format, &[row.clone(), row]).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&parquet_path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+        let schema = batches[0].schema();
+        let names: Vec<&str> = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect();
+        assert_eq!(names, LEDGER_COLUMNS);
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            2
+        );
+
+        assert!(LedgerFormat::from_path("ledger.xlsx").is_err());
+    }
+}
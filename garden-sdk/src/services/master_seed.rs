@@ -0,0 +1,104 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::models::address::ChainAddress;
+use eyre::{Result, WrapErr};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+// Domain separation for secrets derived from a master seed; changing it changes every secret
+const SECRET_KDF_SALT: &[u8] = b"garden-sdk/order-secret/v1";
+
+/// Shortest master seed accepted, in bytes
+pub const MIN_SEED_BYTES: usize = 32;
+
+/// Seed that order secrets are derived from instead of drawn at random
+///
+/// Each secret is HKDF-SHA256 of the seed over the order's initiator source
+/// address and nonce. Both are part of the order the API stores, so the
+/// secrets of every order created with a seed can be rebuilt from the seed alone.
+#[derive(Clone)]
+pub struct MasterSeed(Vec<u8>);
+
+impl fmt::Debug for MasterSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MasterSeed(<redacted>)")
+    }
+}
+
+impl MasterSeed {
+    /// Seed from raw bytes, at least 32 of them
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < MIN_SEED_BYTES {
+            return Err(eyre::eyre!(
+                "Master seed must be at least {} bytes, got {}",
+                MIN_SEED_BYTES,
+                bytes.len()
+            ));
+        }
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Seed from a hex string, with or without `0x`
+    pub fn from_hex(hex_seed: &str) -> Result<Self> {
+        let hex_seed = hex_seed.trim();
+        let bytes = hex::decode(hex_seed.strip_prefix("0x").unwrap_or(hex_seed))
+            .wrap_err("Master seed is not valid hex")?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Seed from a file holding it as hex
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read master seed from {}", path.display()))?;
+        Self::from_hex(&content)
+    }
+
+    /// Secret of the order with this initiator source address and nonce
+    pub fn derive_secret(&self, initiator: &ChainAddress, nonce: u64) -> [u8; 32] {
+        // Canonical address form, so padding and checksum case never change the secret
+        let info = format!("{}:{}", initiator.to_string().to_lowercase(), nonce);
+        let mut secret = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(SECRET_KDF_SALT), &self.0)
+            .expand(info.as_bytes(), &mut secret)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        secret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_one_secret_per_initiator_and_nonce() {
+        let seed = MasterSeed::from_bytes(&[7; 32]).unwrap();
+        let initiator: ChainAddress = "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901"
+            .parse()
+            .unwrap();
+        let lowercase: ChainAddress = "0x3e53d785995bb74c0b9ba8f71d0d6a0c4d9e6901"
+            .parse()
+            .unwrap();
+
+        let secret = seed.derive_secret(&initiator, 1);
+        assert_eq!(secret, seed.derive_secret(&lowercase, 1));
+        assert_ne!(secret, seed.derive_secret(&initiator, 2));
+        assert_ne!(
+            secret,
+            MasterSeed::from_bytes(&[8; 32])
+                .unwrap()
+                .derive_secret(&initiator, 1)
+        );
+
+        let hex_seed = format!("0x{}\n", "07".repeat(32));
+        assert_eq!(
+            MasterSeed::from_hex(&hex_seed)
+                .unwrap()
+                .derive_secret(&initiator, 1),
+            secret
+        );
+        assert!(MasterSeed::from_bytes(&[7; 16]).is_err());
+        assert!(format!("{:?}", seed).contains("redacted"));
+    }
+}
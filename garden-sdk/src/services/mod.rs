@@ -0,0 +1,16 @@
+pub mod api_recorder;
+pub mod db_service;
+pub mod garden_api;
+pub mod ledger_export;
+pub mod master_seed;
+pub mod nonce_manager;
+pub mod onchain_redeem;
+pub mod order_history;
+pub mod order_service;
+pub mod quote_cache;
+pub mod quote_service;
+pub mod reconciler;
+pub mod redeem_daemon;
+pub mod signature_service;
+pub mod starknet_services;
+pub mod timelock;
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use eyre::{Result, WrapErr};
+use tracing::debug;
+
+/// Where order nonces are persisted by default
+pub const DEFAULT_NONCE_FILE: &str = "data/nonces.json";
+
+/// Hands out strictly increasing order nonces per initiator address
+///
+/// Nonces never go below the current time in milliseconds, so they stay
+/// compatible with the timestamp nonces used before. The last nonce per
+/// initiator is written to disk before it is returned, so a restarted process
+/// continues above it. Clones share state and are safe to use across tasks;
+/// processes sharing the file take turns through an advisory lock on
+/// `<file>.lock`. Where no such lock exists (non-Unix targets), file-backed
+/// managers return an error instead of risking duplicate nonces.
+#[derive(Debug, Clone)]
+pub struct NonceManager {
+    path: Option<PathBuf>,
+    // Re-read from `path` under the file lock on every call
+    last: Arc<Mutex<BTreeMap<String, u64>>>,
+}
+
+impl Default for NonceManager {
+    fn default() -> Self {
+        Self::open(DEFAULT_NONCE_FILE)
+    }
+}
+
+impl NonceManager {
+    /// Nonces persisted to a JSON file mapping initiator to last nonce
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+            last: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    /// Nonces kept for the lifetime of this manager only
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            last: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    /// Next nonce for an initiator, above every nonce handed out for it before
+    ///
+    /// Waiting for another process's file lock happens on the blocking pool, so it
+    /// never stalls the async runtime.
+    pub async fn next(&self, initiator: &str) -> Result<u64> {
+        let nonces = self.clone();
+        let initiator = initiator.to_string();
+        tokio::task::spawn_blocking(move || nonces.next_blocking(&initiator))
+            .await
+            .wrap_err("Nonce task failed")?
+    }
+
+    fn next_blocking(&self, initiator: &str) -> Result<u64> {
+        let mut last = self
+            .last
+            .lock()
+            .map_err(|_| eyre::eyre!("Nonce state was poisoned by a panic"))?;
+        // Held until the new nonce is on disk, so another process never reads a stale file
+        let _lock = match &self.path {
+            Some(path) => {
+                let lock = lock_exclusive(&path.with_extension("json.lock"))?;
+                *last = self.load()?;
+                Some(lock)
+            }
+            None => None,
+        };
+
+        let key = initiator.trim().to_lowercase();
+        let now = chrono::Utc::now().timestamp_millis().max(0) as u64;
+        let nonce = last
+            .get(&key)
+            .map_or(now, |&previous| now.max(previous + 1));
+        last.insert(key, nonce);
+
+        if let Some(path) = &self.path {
+            if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+            // Write then rename so a crash never leaves a truncated file behind
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_string_pretty(&*last)?)?;
+            fs::rename(&tmp, path)
+                .wrap_err_with(|| format!("Failed to persist nonces to {}", path.display()))?;
+        }
+        debug!("🔢 Nonce {} for initiator {}", nonce, initiator);
+        Ok(nonce)
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, u64>> {
+        match &self.path {
+            Some(path) if path.exists() => {
+                let content = fs::read_to_string(path)?;
+                serde_json::from_str(&content)
+                    .wrap_err_with(|| format!("Invalid nonce file {}", path.display()))
+            }
+            _ => Ok(BTreeMap::new()),
+        }
+    }
+}
+
+// Take an advisory exclusive lock on `path`, blocking until it is free. The nonce file
+// itself is replaced on every write, so the lock lives on a sidecar file whose inode
+// never changes. The lock is released when the returned file is closed.
+#[cfg(unix)]
+fn lock_exclusive(path: &Path) -> Result<File> {
+    use std::os::fd::AsRawFd;
+
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .wrap_err_with(|| format!("Failed to open nonce lock {}", path.display()))?;
+
+    // SAFETY: the descriptor belongs to `file`, which outlives the call
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+        return Err(std::io::Error::last_os_error())
+            .wrap_err_with(|| format!("Failed to lock {}", path.display()));
+    }
+    Ok(file)
+}
+
+// Without a cross-process lock two processes could hand out the same nonce, so a
+// persisted nonce file is refused rather than shared unguarded
+#[cfg(not(unix))]
+fn lock_exclusive(path: &Path) -> Result<File> {
+    Err(eyre::eyre!(
+        "Locking the nonce file {} is not supported on this platform; use an in-memory nonce manager",
+        path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn nonces_increase_across_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nonces.json");
+
+        let initiator = "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901";
+        let nonces = NonceManager::open(&path);
+        let first = nonces.next(initiator).await.unwrap();
+        let second = nonces
+            .clone()
+            .next(&initiator.to_lowercase())
+            .await
+            .unwrap();
+        assert!(second > first);
+
+        // A fresh manager on the same file continues above the persisted nonce
+        let third = NonceManager::open(&path).next(initiator).await.unwrap();
+        assert!(third > second);
+
+        // Managers sharing the file, like separate processes, never hand out the same nonce
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let nonces = NonceManager::open(&path);
+                tokio::spawn(async move {
+                    let mut handed_out = Vec::new();
+                    for _ in 0..25 {
+                        handed_out.push(nonces.next(initiator).await.unwrap());
+                    }
+                    handed_out
+                })
+            })
+            .collect();
+        let mut all = Vec::new();
+        for handle in handles {
+            all.extend(handle.await.unwrap());
+        }
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), 100);
+        assert!(all[0] > third);
+    }
+}
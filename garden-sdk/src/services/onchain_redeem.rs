@@ -0,0 +1,382 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::config::settings::evm_network;
+use crate::models::matched_order::SwapData;
+use crate::models::secret::{SecretScheme, u32_words};
+use crate::services::starknet_services::{
+    StarknetAccountType, get_signer_and_account, starknet_provider,
+};
+use alloy::{
+    network::{Ethereum, EthereumWallet},
+    primitives::{Address, B256, Bytes},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    sol,
+    transports::http::Http,
+};
+use eyre::{Result, WrapErr};
+use reqwest::{Client, Url};
+use serde::Serialize;
+use starknet::accounts::{Account, SingleOwnerAccount};
+use starknet::core::types::{
+    BlockId, BlockTag, Call, ExecutionResult, FunctionCall, TransactionStatus,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::{JsonRpcClient, Provider as _, jsonrpc::HttpTransport};
+use starknet::signers::LocalWallet;
+use starknet_crypto::Felt;
+use tracing::info;
+
+// How often and how many times a sent Starknet transaction is polled for its status
+const STARKNET_TX_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const STARKNET_TX_POLLS: u32 = 60;
+
+sol! {
+    #[sol(rpc)]
+    interface GardenHTLC {
+        function redeem(bytes32 orderID, bytes calldata secret) external;
+        function refund(bytes32 orderID) external;
+    }
+}
+
+/// Starknet account that sends direct redeem and refund transactions
+///
+/// Transactions are signed with the owner key alone, so Argent accounts with a
+/// guardian are rejected.
+#[derive(Clone)]
+pub struct StarknetRedeemKey {
+    /// Hex encoded account private key
+    pub private_key: String,
+    /// Account contract address
+    pub account: String,
+    pub account_type: StarknetAccountType,
+}
+
+/// Keys that pay for calling HTLCs on chain: redeems when the relayer fails, and refunds
+///
+/// Anyone may call an HTLC's `redeem`, or its `refund` once the timelock has
+/// expired. Funds always go to the swap's redeemer or initiator, so these only
+/// need gas on the chain of the swap.
+#[derive(Clone, Default)]
+pub struct DirectRedeemKeys {
+    /// Hex encoded private key for EVM destinations
+    pub evm_private_key: Option<String>,
+    pub starknet: Option<StarknetRedeemKey>,
+}
+
+impl fmt::Debug for DirectRedeemKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirectRedeemKeys")
+            .field("evm", &self.evm_private_key.is_some())
+            .field(
+                "starknet_account",
+                &self.starknet.as_ref().map(|key| &key.account),
+            )
+            .finish()
+    }
+}
+
+impl DirectRedeemKeys {
+    pub fn is_empty(&self) -> bool {
+        self.evm_private_key.is_none() && self.starknet.is_none()
+    }
+}
+
+/// Redeem a destination swap by calling its HTLC directly, returning the transaction hash
+pub async fn redeem_on_chain(
+    swap: &SwapData,
+    secret: &str,
+    keys: &DirectRedeemKeys,
+) -> Result<String> {
+    let swap_id = swap
+        .swap_id
+        .as_deref()
+        .ok_or_else(|| eyre::eyre!("No swap id reported for the {} swap", swap.chain))?;
+    let secret = hex::decode(secret.trim_start_matches("0x")).wrap_err("Invalid secret")?;
+
+    match SecretScheme::for_chain(&swap.chain) {
+        SecretScheme::Evm => {
+            let key = keys.evm_private_key.as_deref().ok_or_else(|| {
+                eyre::eyre!("An EVM private key is required to redeem on {}", swap.chain)
+            })?;
+            redeem_evm(swap, swap_id, secret, key).await
+        }
+        SecretScheme::Starknet => {
+            let key = keys.starknet.as_ref().ok_or_else(|| {
+                eyre::eyre!("A Starknet account is required to redeem on {}", swap.chain)
+            })?;
+            redeem_starknet(swap, swap_id, secret, key).await
+        }
+        SecretScheme::Bitcoin => Err(eyre::eyre!(
+            "Direct redeem is not supported on {}",
+            swap.chain
+        )),
+    }
+}
+
+// Wallet-filled provider and HTLC address for calling the EVM HTLC of a swap
+fn evm_htlc(
+    swap: &SwapData,
+    key: &str,
+) -> Result<(Address, impl Provider<Http<Client>, Ethereum>)> {
+    let rpc_url = evm_network(&swap.chain)
+        .map(|network| network.rpc_url)
+        .ok_or_else(|| eyre::eyre!("No EVM RPC configured for chain: {}", swap.chain))?;
+    let htlc = swap
+        .asset
+        .as_evm()
+        .ok_or_else(|| eyre::eyre!("Invalid EVM HTLC address: {}", swap.asset))?;
+    let signer = PrivateKeySigner::from_str(key).wrap_err("Invalid EVM private key")?;
+
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::from(signer))
+        .on_http(Url::parse(rpc_url).wrap_err_with(|| format!("Invalid RPC url: {}", rpc_url))?);
+    Ok((htlc, provider))
+}
+
+// Call `redeem(orderID, secret)` on the EVM HTLC and wait for the receipt
+async fn redeem_evm(swap: &SwapData, swap_id: &str, secret: Vec<u8>, key: &str) -> Result<String> {
+    let (htlc, provider) = evm_htlc(swap, key)?;
+    let swap_id = B256::from_str(swap_id).wrap_err("Invalid EVM swap id")?;
+
+    info!("⛓️ Redeeming swap {} on {} directly", swap_id, swap.chain);
+    let receipt = GardenHTLC::new(htlc, provider)
+        .redeem(swap_id, Bytes::from(secret))
+        .send()
+        .await
+        .wrap_err("Failed to send redeem transaction")?
+        .get_receipt()
+        .await
+        .wrap_err("Failed to get redeem receipt")?;
+    if !receipt.status() {
+        return Err(eyre::eyre!(
+            "Redeem transaction {} reverted",
+            receipt.transaction_hash
+        ));
+    }
+    Ok(receipt.transaction_hash.to_string())
+}
+
+// Account that sends direct Starknet transactions, signed by the owner key alone
+//
+// An Argent guardian must co-sign every transaction, which these keys cannot do,
+// so accounts with one are turned away before anything is sent
+async fn starknet_sender(
+    chain: &str,
+    key: &StarknetRedeemKey,
+) -> Result<SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>> {
+    let private_key = Felt::from_hex(&key.private_key).wrap_err("Invalid Starknet private key")?;
+    let address = Felt::from_hex(&key.account)
+        .wrap_err_with(|| format!("Invalid Starknet account address: {}", key.account))?;
+
+    let guardian_getter = match key.account_type {
+        StarknetAccountType::Argent => Some("get_guardian"),
+        StarknetAccountType::ArgentCairo0 => Some("getGuardian"),
+        _ => None,
+    };
+    if let Some(getter) = guardian_getter {
+        let guardian = starknet_provider(chain)?
+            .call(
+                FunctionCall {
+                    contract_address: address,
+                    entry_point_selector: get_selector_from_name(getter)?,
+                    calldata: vec![],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await
+            .map_err(|e| eyre::eyre!("Failed to read the guardian of {}: {}", key.account, e))?;
+        if guardian
+            .first()
+            .is_some_and(|guardian| *guardian != Felt::ZERO)
+        {
+            return Err(eyre::eyre!(
+                "Argent account {} has a guardian, which must co-sign its transactions; \
+                 send direct redeems and refunds from an account without one",
+                key.account
+            ));
+        }
+    }
+
+    let (_, account) =
+        get_signer_and_account(private_key, address, chain, key.account_type).await?;
+    Ok(account)
+}
+
+// Invoke `redeem(order_id, secret)` on the Starknet HTLC, with the secret as `Array<u32>`,
+// and wait for it to be accepted
+async fn redeem_starknet(
+    swap: &SwapData,
+    swap_id: &str,
+    secret: Vec<u8>,
+    key: &StarknetRedeemKey,
+) -> Result<String> {
+    let htlc = swap
+        .asset
+        .as_starknet()
+        .ok_or_else(|| eyre::eyre!("Invalid Starknet HTLC address: {}", swap.asset))?;
+    let secret: [u8; 32] = secret
+        .try_into()
+        .map_err(|_| eyre::eyre!("Starknet redeems need a 32 byte secret"))?;
+    let account = starknet_sender(&swap.chain, key).await?;
+
+    let words = u32_words(&secret);
+    let mut calldata = vec![
+        Felt::from_hex(swap_id).wrap_err("Invalid Starknet swap id")?,
+        Felt::from(words.len()),
+    ];
+    calldata.extend(words.iter().map(|word| Felt::from(*word)));
+
+    info!("⛓️ Redeeming swap {} on {} directly", swap_id, swap.chain);
+    let result = account
+        .execute_v3(vec![Call {
+            to: htlc,
+            selector: get_selector_from_name("redeem")?,
+            calldata,
+        }])
+        .send()
+        .await
+        .map_err(|e| eyre::eyre!("Failed to send redeem transaction: {}", e))?;
+    confirm_starknet_tx(&swap.chain, result.transaction_hash, "Redeem").await?;
+    Ok(format!("{:#x}", result.transaction_hash))
+}
+
+// Poll a sent Starknet transaction until it is accepted, failing if it is
+// rejected or reverts, like the EVM paths check their receipt
+async fn confirm_starknet_tx(chain: &str, tx_hash: Felt, action: &str) -> Result<()> {
+    let provider = starknet_provider(chain)?;
+    for _ in 0..STARKNET_TX_POLLS {
+        match provider.get_transaction_status(tx_hash).await {
+            Ok(
+                TransactionStatus::AcceptedOnL2(result) | TransactionStatus::AcceptedOnL1(result),
+            ) => {
+                return match result {
+                    ExecutionResult::Succeeded => Ok(()),
+                    ExecutionResult::Reverted { reason } => Err(eyre::eyre!(
+                        "{} transaction {:#x} reverted: {}",
+                        action,
+                        tx_hash,
+                        reason
+                    )),
+                };
+            }
+            Ok(TransactionStatus::Rejected) => {
+                return Err(eyre::eyre!(
+                    "{} transaction {:#x} was rejected",
+                    action,
+                    tx_hash
+                ));
+            }
+            // Not seen by the node yet, or still pending
+            Ok(TransactionStatus::Received) | Err(_) => {}
+        }
+        tokio::time::sleep(STARKNET_TX_POLL_INTERVAL).await;
+    }
+    Err(eyre::eyre!(
+        "{} transaction {:#x} was not accepted after {:?}",
+        action,
+        tx_hash,
+        STARKNET_TX_POLL_INTERVAL * STARKNET_TX_POLLS
+    ))
+}
+
+/// The `refund(orderID)` call that returns an expired swap to its initiator
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HtlcRefund {
+    pub chain: String,
+    /// HTLC contract holding the swap
+    pub htlc: String,
+    pub swap_id: String,
+}
+
+impl HtlcRefund {
+    pub fn for_swap(swap: &SwapData) -> Result<Self> {
+        let swap_id = swap
+            .swap_id
+            .clone()
+            .ok_or_else(|| eyre::eyre!("No swap id reported for the {} swap", swap.chain))?;
+        Ok(Self {
+            chain: swap.chain.clone(),
+            htlc: swap.asset.to_string(),
+            swap_id,
+        })
+    }
+}
+
+/// Refund an expired swap by calling its HTLC directly, returning the transaction hash
+///
+/// The HTLC rejects the call until the swap's timelock has expired.
+pub async fn refund_on_chain(swap: &SwapData, keys: &DirectRedeemKeys) -> Result<String> {
+    let refund = HtlcRefund::for_swap(swap)?;
+
+    match SecretScheme::for_chain(&swap.chain) {
+        SecretScheme::Evm => {
+            let key = keys.evm_private_key.as_deref().ok_or_else(|| {
+                eyre::eyre!("An EVM private key is required to refund on {}", swap.chain)
+            })?;
+            refund_evm(swap, &refund.swap_id, key).await
+        }
+        SecretScheme::Starknet => {
+            let key = keys.starknet.as_ref().ok_or_else(|| {
+                eyre::eyre!("A Starknet account is required to refund on {}", swap.chain)
+            })?;
+            refund_starknet(swap, &refund.swap_id, key).await
+        }
+        SecretScheme::Bitcoin => Err(eyre::eyre!(
+            "Direct refund is not supported on {}",
+            swap.chain
+        )),
+    }
+}
+
+// Call `refund(orderID)` on the EVM HTLC and wait for the receipt
+async fn refund_evm(swap: &SwapData, swap_id: &str, key: &str) -> Result<String> {
+    let (htlc, provider) = evm_htlc(swap, key)?;
+    let swap_id = B256::from_str(swap_id).wrap_err("Invalid EVM swap id")?;
+
+    info!("⛓️ Refunding swap {} on {} directly", swap_id, swap.chain);
+    let receipt = GardenHTLC::new(htlc, provider)
+        .refund(swap_id)
+        .send()
+        .await
+        .wrap_err("Failed to send refund transaction")?
+        .get_receipt()
+        .await
+        .wrap_err("Failed to get refund receipt")?;
+    if !receipt.status() {
+        return Err(eyre::eyre!(
+            "Refund transaction {} reverted",
+            receipt.transaction_hash
+        ));
+    }
+    Ok(receipt.transaction_hash.to_string())
+}
+
+// Invoke `refund(order_id)` on the Starknet HTLC and wait for it to be accepted
+async fn refund_starknet(
+    swap: &SwapData,
+    swap_id: &str,
+    key: &StarknetRedeemKey,
+) -> Result<String> {
+    let htlc = swap
+        .asset
+        .as_starknet()
+        .ok_or_else(|| eyre::eyre!("Invalid Starknet HTLC address: {}", swap.asset))?;
+    let account = starknet_sender(&swap.chain, key).await?;
+
+    info!("⛓️ Refunding swap {} on {} directly", swap_id, swap.chain);
+    let result = account
+        .execute_v3(vec![Call {
+            to: htlc,
+            selector: get_selector_from_name("refund")?,
+            calldata: vec![Felt::from_hex(swap_id).wrap_err("Invalid Starknet swap id")?],
+        }])
+        .send()
+        .await
+        .map_err(|e| eyre::eyre!("Failed to send refund transaction: {}", e))?;
+    confirm_starknet_tx(&swap.chain, result.transaction_hash, "Refund").await?;
+    Ok(format!("{:#x}", result.transaction_hash))
+}
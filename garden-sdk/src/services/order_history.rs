@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::models::address::ChainAddress;
+use crate::models::matched_order::MatchedOrder;
+use crate::models::order::OrderStatus;
+use crate::services::order_service::OrderService;
+use bigdecimal::BigDecimal;
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// Where matched orders are cached for `history` by default
+pub const DEFAULT_HISTORY_FILE: &str = "data/order_history.json";
+
+/// A matched order as last fetched from the API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub order: MatchedOrder,
+    /// Unix time the order was first fetched into the history
+    pub first_seen: i64,
+    /// Unix time of the latest fetch
+    pub refreshed_at: i64,
+}
+
+impl HistoryEntry {
+    /// Creation time reported by the API, else when the order was first fetched
+    pub fn created_at(&self) -> i64 {
+        self.order.created_at().unwrap_or(self.first_seen)
+    }
+
+    pub fn status(&self) -> OrderStatus {
+        self.order.status()
+    }
+}
+
+/// Which history entries to list; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    /// `source::destination`, each side a chain or `chain:asset`
+    pub pair: Option<String>,
+    /// Earliest creation time, Unix seconds, inclusive
+    pub from: Option<i64>,
+    /// Latest creation time, Unix seconds, inclusive
+    pub to: Option<i64>,
+    pub status: Option<OrderStatus>,
+    pub strategy: Option<String>,
+    /// Bounds on the source amount in the asset's smallest unit, inclusive
+    pub min_amount: Option<BigDecimal>,
+    pub max_amount: Option<BigDecimal>,
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        let order = &entry.order.create_order;
+        let created_at = entry.created_at();
+
+        if let Some(pair) = &self.pair {
+            let (source, destination) = pair.split_once("::").unwrap_or((pair.as_str(), ""));
+            if !leg_matches(source, &order.source_chain, &order.source_asset)
+                || !leg_matches(
+                    destination,
+                    &order.destination_chain,
+                    &order.destination_asset,
+                )
+            {
+                return false;
+            }
+        }
+        self.from.is_none_or(|from| created_at >= from)
+            && self.to.is_none_or(|to| created_at <= to)
+            && self.status.is_none_or(|status| entry.status() == status)
+            && self
+                .strategy
+                .as_ref()
+                .is_none_or(|strategy| &order.additional_data.strategy_id == strategy)
+            && self
+                .min_amount
+                .as_ref()
+                .is_none_or(|min| &order.source_amount >= min)
+            && self
+                .max_amount
+                .as_ref()
+                .is_none_or(|max| &order.source_amount <= max)
+    }
+}
+
+// An empty side matches any chain; assets are compared as parsed addresses, so
+// checksum case and Starknet padding do not matter
+fn leg_matches(filter: &str, chain: &str, asset: &ChainAddress) -> bool {
+    match filter.split_once(':') {
+        _ if filter.is_empty() => true,
+        Some((filter_chain, filter_asset)) => {
+            filter_chain == chain
+                && filter_asset.parse::<ChainAddress>().ok().as_ref() == Some(asset)
+        }
+        None => filter == chain,
+    }
+}
+
+/// One page of matching entries, newest first
+#[derive(Debug, Clone)]
+pub struct HistoryPage<'a> {
+    pub entries: Vec<(&'a str, &'a HistoryEntry)>,
+    /// 1-based page number
+    pub page: usize,
+    pub total_pages: usize,
+    /// Entries matching the filter across all pages
+    pub total: usize,
+}
+
+/// Orders fetched so far, kept locally so they can be browsed without the API
+#[derive(Debug, Clone, Default)]
+pub struct OrderHistory {
+    path: PathBuf,
+    entries: BTreeMap<String, HistoryEntry>,
+}
+
+impl OrderHistory {
+    /// Load the history kept at `path`, empty if it does not exist yet
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let entries = if path.exists() {
+            let content = fs::read_to_string(&path)?;
+            serde_json::from_str(&content)
+                .wrap_err_with(|| format!("Invalid order history {}", path.display()))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self { path, entries })
+    }
+
+    pub fn get(&self, order_id: &str) -> Option<&HistoryEntry> {
+        self.entries.get(order_id)
+    }
+
+    pub fn order_ids(&self) -> impl Iterator<Item = &String> {
+        self.entries.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Record the latest details of an order
+    pub fn insert(&mut self, order_id: &str, order: MatchedOrder, now: i64) {
+        let first_seen = self
+            .entries
+            .get(order_id)
+            .map_or(now, |entry| entry.first_seen);
+        self.entries.insert(
+            order_id.to_string(),
+            HistoryEntry {
+                order,
+                first_seen,
+                refreshed_at: now,
+            },
+        );
+    }
+
+    /// Fetch the matched details of each order and save them, returning how many
+    /// were updated; orders the API cannot return keep their last known details
+    pub async fn refresh(
+        &mut self,
+        orders: &OrderService,
+        order_ids: impl IntoIterator<Item = String>,
+        concurrency: usize,
+    ) -> Result<usize> {
+        let now = chrono::Utc::now().timestamp();
+        let mut results = futures::stream::iter(order_ids)
+            .map(|order_id| async move {
+                let details = orders.get_order_details(&order_id).await;
+                (order_id, details)
+            })
+            .buffer_unordered(concurrency.max(1));
+
+        let mut updated = 0;
+        while let Some((order_id, details)) = results.next().await {
+            match details {
+                Ok(details) => {
+                    self.insert(&order_id, details.result, now);
+                    updated += 1;
+                }
+                Err(e) => warn!("❌ Failed to refresh order {}: {}", order_id, e),
+            }
+        }
+        self.save()?;
+        info!(
+            "🔄 Refreshed {} orders into {}",
+            updated,
+            self.path.display()
+        );
+        Ok(updated)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        // Write then rename so a crash never leaves a truncated file behind
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&self.entries)?)?;
+        fs::rename(&tmp, &self.path)
+            .wrap_err_with(|| format!("Failed to save order history to {}", self.path.display()))
+    }
+
+    /// Entries matching `filter`, newest first, split into pages of `page_size`
+    pub fn query(&self, filter: &HistoryFilter, page: usize, page_size: usize) -> HistoryPage<'_> {
+        let page_size = page_size.max(1);
+        let mut matching: Vec<(&str, &HistoryEntry)> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| filter.matches(entry))
+            .map(|(order_id, entry)| (order_id.as_str(), entry))
+            .collect();
+        matching.sort_by(|a, b| b.1.created_at().cmp(&a.1.created_at()).then(a.0.cmp(b.0)));
+
+        let total = matching.len();
+        let total_pages = total.div_ceil(page_size).max(1);
+        let page = page.clamp(1, total_pages);
+        let entries = matching
+            .into_iter()
+            .skip((page - 1) * page_size)
+            .take(page_size)
+            .collect();
+        HistoryPage {
+            entries,
+            page,
+            total_pages,
+            total,
+        }
+    }
+}
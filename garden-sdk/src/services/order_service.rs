@@ -0,0 +1,900 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::models::additional_data::AdditonalData;
+use crate::models::address::ChainAddress;
+use crate::models::matched_order::{MatchedOrder, OrderDetails};
+use crate::models::order::{ApiResponse, Order, OrderStatus, OrderVerification, Paginated, Status};
+use crate::models::order_params::{OrderParamOverrides, OrderParams, ParamLimits};
+use crate::models::quote::{
+    InitiateParams, InitiateRequest, PerformOn, Quote, RedeemRequest, RedeemResult, RedeemRoute,
+};
+use crate::models::secret::{SecretHash, SecretScheme};
+use crate::services::garden_api::{GardenApi, ReqwestGardenApi};
+use crate::services::master_seed::MasterSeed;
+use crate::services::nonce_manager::NonceManager;
+use crate::services::onchain_redeem::{
+    DirectRedeemKeys, HtlcRefund, redeem_on_chain, refund_on_chain,
+};
+use crate::services::quote_service::QuoteService;
+use crate::services::signature_service::sign_initiate;
+use crate::services::starknet_services::StarknetAccountConfig;
+use crate::utils::file_utils::{self};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::TimeDelta;
+use eyre::{Result, WrapErr};
+use hex;
+use rand::Rng;
+use tracing::{info, warn};
+
+/// Seconds before its deadline at which an order is re-attested instead of submitted
+pub const DEADLINE_REFRESH_MARGIN_SECS: i64 = 60;
+
+/// Lookups made before an order counts as missing during verification
+pub const VERIFY_ATTEMPTS: u32 = 3;
+
+/// Time allowed for a single verification lookup
+pub const VERIFY_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(700);
+
+// Base delay between verification lookups, plus up to the same again as jitter
+const VERIFY_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(200);
+
+// Redeem tx hash from a relayer response such as `{"status":"Ok","result":"0x.."}`
+fn relayer_tx_hash(text: &str) -> Result<String> {
+    let response: ApiResponse<String> = serde_json::from_str(text)
+        .map_err(|e| eyre::eyre!("Invalid relayer response: {}. Raw body: {}", e, text))?;
+    match (response.status, response.data) {
+        (Status::Ok, Some(tx_hash)) if !tx_hash.trim().is_empty() => Ok(tx_hash),
+        (Status::Ok, _) => Err(eyre::eyre!("Relayer response has no tx hash: {}", text)),
+        (Status::Error, _) => Err(eyre::eyre!(
+            "Relayer rejected the redeem: {}",
+            response.error.unwrap_or_default()
+        )),
+    }
+}
+
+// Order amounts are positive integers in the asset's smallest unit
+fn parse_order_amount(side: &str, amount: &str) -> Result<BigDecimal> {
+    let parsed = BigDecimal::from_str(amount.trim())
+        .map_err(|_| eyre::eyre!("Failed to parse {} amount: {}", side, amount))?;
+    if parsed <= BigDecimal::from(0) || !parsed.is_integer() {
+        return Err(eyre::eyre!(
+            "Invalid {} amount {}: expected a positive integer",
+            side,
+            amount
+        ));
+    }
+    Ok(parsed)
+}
+
+// Deadline for an order attested now
+fn order_deadline(minutes: u64) -> i64 {
+    chrono::Utc::now()
+        .checked_add_signed(TimeDelta::minutes(minutes as i64))
+        .unwrap()
+        .timestamp()
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderService {
+    api: Arc<dyn GardenApi>,
+    quotes: QuoteService,
+    max_slippage_bps: Option<u32>,
+    order_params: OrderParamOverrides,
+    param_limits: ParamLimits,
+    nonces: NonceManager,
+    master_seed: Option<MasterSeed>,
+    direct_redeem: Option<DirectRedeemKeys>,
+}
+
+impl Default for OrderService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrderService {
+    /// Service talking to the Garden testnet relay and orderbook
+    pub fn new() -> Self {
+        Self::with_api(Arc::new(ReqwestGardenApi::testnet()))
+    }
+
+    /// Service sending its requests through the given API implementation
+    pub fn with_api(api: Arc<dyn GardenApi>) -> Self {
+        Self {
+            quotes: QuoteService::with_api(api.clone()),
+            api,
+            max_slippage_bps: None,
+            order_params: OrderParamOverrides::default(),
+            param_limits: ParamLimits::default(),
+            nonces: NonceManager::default(),
+            master_seed: None,
+            direct_redeem: None,
+        }
+    }
+
+    /// Redeem on chain with these keys whenever the relayer fails, and refund with them
+    pub fn with_direct_redeem(mut self, keys: DirectRedeemKeys) -> Self {
+        self.direct_redeem = Some(keys);
+        self
+    }
+
+    /// Derive order secrets from a master seed instead of drawing them at random
+    pub fn with_master_seed(mut self, seed: MasterSeed) -> Self {
+        self.master_seed = Some(seed);
+        self
+    }
+
+    /// Use a different nonce store than `data/nonces.json`
+    pub fn with_nonce_manager(mut self, nonces: NonceManager) -> Self {
+        self.nonces = nonces;
+        self
+    }
+
+    /// Replace the per-chain timelock, fee, confirmation and deadline defaults
+    pub fn with_order_params(mut self, overrides: OrderParamOverrides) -> Self {
+        self.order_params = overrides;
+        self
+    }
+
+    /// Check order parameters against the ranges the relay accepts
+    pub fn with_param_limits(mut self, limits: ParamLimits) -> Self {
+        self.param_limits = limits;
+        self
+    }
+
+    /// Abort order creation when attested prices move more than `bps` from the quote
+    pub fn with_max_slippage_bps(mut self, bps: u32) -> Self {
+        self.max_slippage_bps = Some(bps);
+        self
+    }
+
+    /// Treat quotes as current for this many seconds after they are fetched
+    pub fn with_quote_validity(mut self, secs: i64) -> Self {
+        self.quotes = self.quotes.with_validity(secs);
+        self
+    }
+
+    /// Quote client sharing this service's API implementation
+    pub fn quotes(&self) -> &QuoteService {
+        &self.quotes
+    }
+
+    // Generate a secret and its hash for the order, derived from the master seed if set
+    fn gen_secret(
+        &self,
+        source_chain: &str,
+        initiator: &ChainAddress,
+        nonce: u64,
+    ) -> (String, String) {
+        use rand::{RngCore, rngs::OsRng};
+
+        let secret = match &self.master_seed {
+            Some(seed) => seed.derive_secret(initiator, nonce),
+            None => {
+                let mut secret = [0u8; 32];
+                OsRng.fill_bytes(&mut secret);
+                secret
+            }
+        };
+
+        let hash = SecretScheme::for_chain(source_chain).hash(&secret);
+        (hex::encode(secret), hash.to_string())
+    }
+
+    /// Create an order from the best strategy of a quote, using its attested prices
+    pub async fn create_order(&self, quote: &Quote) -> Result<(String, String)> {
+        let (mut order, secret) = self.build_order(quote).await?;
+
+        // Log the destination amount being used
+        info!("💰 Using destination amount: {}", order.destination_amount);
+
+        self.apply_attestation(&mut order, quote).await?;
+
+        info!("⏳ Adding small delay before create order request...");
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+        // Slow attestations can eat into the deadline; never submit one about to lapse
+        let remaining = order.additional_data.deadline - chrono::Utc::now().timestamp();
+        if remaining < DEADLINE_REFRESH_MARGIN_SECS {
+            info!(
+                "⏰ Order deadline is {}s away, re-attesting with a fresh deadline",
+                remaining
+            );
+            let params = OrderParams::for_pair(
+                &order.source_chain,
+                &order.destination_chain,
+                &self.order_params,
+            );
+            order.additional_data.deadline = order_deadline(params.deadline_minutes);
+            self.apply_attestation(&mut order, quote).await?;
+        }
+
+        info!("📤 Sending create order request...");
+        let response = self.api.create_order(&order).await?;
+        info!("✅ Successfully parsed API response");
+        match response.status {
+            Status::Ok => {
+                if let Some(order_id) = response.data {
+                    info!("💾 Saving order data to file...");
+                    file_utils::save_order_data(&order_id, &secret)?;
+                    let mut order_ids = file_utils::load_order_ids().unwrap_or_else(|_| Vec::new());
+                    order_ids.push(order_id.clone());
+                    file_utils::save_order_ids(&order_ids)?;
+                    info!(
+                        "✅ Successfully saved order data: order_id={}, secret={}",
+                        order_id, secret
+                    );
+                    Ok((order_id, secret))
+                } else {
+                    warn!("❌ No order ID in response");
+                    Err(eyre::eyre!("No order ID in response"))
+                }
+            }
+            Status::Error => {
+                let error_msg = response.error.clone().unwrap_or_default();
+                warn!("❌ API returned error status: {}", error_msg);
+                Err(eyre::eyre!("API returned error status: {}", error_msg))
+            }
+        }
+    }
+
+    // Attest the order and copy the attested signature and prices into it,
+    // refusing attestations that moved too far from the quote
+    async fn apply_attestation(&self, order: &mut Order, quote: &Quote) -> Result<()> {
+        info!("🔍 Getting attested quote...");
+        let attested = self.quotes.attest(order).await?;
+        info!("✅ Received attested quote :{:#?}", attested);
+
+        let deviation = attested.deviation_from(quote);
+        info!(
+            "📐 Order nonce {}: attested price deviation from quote: input {:.1} bps, output {:.1} bps, destination {:.1} bps",
+            order.nonce, deviation.input_bps, deviation.output_bps, deviation.destination_bps
+        );
+        if let Some(max_bps) = self.max_slippage_bps {
+            if deviation.max_bps() > f64::from(max_bps) {
+                warn!(
+                    "❌ Attested prices moved {:.1} bps from the quote, limit is {} bps",
+                    deviation.max_bps(),
+                    max_bps
+                );
+                return Err(eyre::eyre!(
+                    "Attested prices moved {:.1} bps from the quote, beyond the {} bps limit",
+                    deviation.max_bps(),
+                    max_bps
+                ));
+            }
+        }
+
+        info!("📝 Updating order with attested data...");
+        order.additional_data.sig = Some(attested.sig);
+        order.additional_data.input_token_price = attested.input_token_price;
+        order.additional_data.output_token_price = attested.output_token_price;
+        info!("✅ Order updated with attested data");
+        Ok(())
+    }
+
+    /// Build an order and its secret from a quote without contacting the API
+    pub async fn build_order(&self, quote: &Quote) -> Result<(Order, String)> {
+        let order_pair = quote.order_pair.as_str();
+        let strategy_id = quote.best().strategy_id.clone();
+        let (input_token_price, output_token_price) =
+            (quote.input_token_price, quote.output_token_price);
+        info!("🎯 Creating new order with strategy ID: {}", strategy_id);
+
+        // Parse the order pair to extract chain and asset information
+        info!("🔍 Parsing order pair: {}", order_pair);
+        let parts: Vec<&str> = order_pair.split("::").collect();
+        if parts.len() != 2 {
+            return Err(eyre::eyre!("Invalid order pair format: {}", order_pair));
+        }
+
+        let (src, dst) = (parts[0], parts[1]);
+        let src_parts: Vec<&str> = src.split(':').collect();
+        let dst_parts: Vec<&str> = dst.split(':').collect();
+
+        if src_parts.len() != 2 || dst_parts.len() != 2 {
+            return Err(eyre::eyre!(
+                "Invalid chain:asset format in order pair: {}",
+                order_pair
+            ));
+        }
+
+        let source_chain = src_parts[0];
+        let source_asset = src_parts[1];
+        let destination_chain = dst_parts[0];
+        let destination_asset = dst_parts[1];
+
+        info!("📊 Parsed order details:");
+        info!("  🔹 Source chain: {}", source_chain);
+        info!("  🔹 Source asset: {}", source_asset);
+        info!("  🔹 Destination chain: {}", destination_chain);
+        info!("  🔹 Destination asset: {}", destination_asset);
+
+        // Set the correct initiator addresses based on source chain
+        let (initiator_source_address, initiator_destination_address) = if source_chain
+            .starts_with("starknet")
+        {
+            // If source is Starknet, use Starknet address as source and EVM address as destination
+            (
+                "0x056b3ebec13503cb1e1d9691f13fdc9b4ae7015765113345a7355add1e29d7dc".to_string(),
+                "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901".to_string(),
+            )
+        } else {
+            // Otherwise (e.g., for Arbitrum), use EVM address as source and Starknet address as destination
+            (
+                "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901".to_string(),
+                "0x056b3ebec13503cb1e1d9691f13fdc9b4ae7015765113345a7355add1e29d7dc".to_string(),
+            )
+        };
+
+        info!("👤 Using initiator addresses:");
+        info!("  🔹 Source: {}", initiator_source_address);
+        info!("  🔹 Destination: {}", initiator_destination_address);
+
+        // Log all key parameters before parsing amounts
+        info!("[ORDER PARAMS] amount: {}", quote.amount);
+        info!("[ORDER PARAMS] order_pair: {}", order_pair);
+        info!("[ORDER PARAMS] strategy_id: {}", strategy_id);
+        info!("[ORDER PARAMS] input_token_price: {}", input_token_price);
+        info!("[ORDER PARAMS] output_token_price: {}", output_token_price);
+        info!("[ORDER PARAMS] exact_out: {}", quote.exact_out);
+
+        let params = OrderParams::for_pair(source_chain, destination_chain, &self.order_params);
+        params.validate(source_chain, destination_chain, &self.param_limits)?;
+        info!("[ORDER PARAMS] timelock: {} blocks", params.timelock);
+        info!("[ORDER PARAMS] fee: {}", params.fee);
+        info!(
+            "[ORDER PARAMS] min_destination_confirmations: {}",
+            params.min_destination_confirmations
+        );
+        info!(
+            "[ORDER PARAMS] deadline: {} minutes",
+            params.deadline_minutes
+        );
+        // Exact-in quotes fix the source amount, exact-out quotes the destination amount;
+        // the strategy supplies the other side
+        let source_amount = parse_order_amount("source", &quote.best().source_amount)?;
+        let destination_amount =
+            parse_order_amount("destination", &quote.best().destination_amount)?;
+        if quote.exact_out {
+            info!(
+                "💰 Exact out: {} destination requires {} source",
+                destination_amount, source_amount
+            );
+        }
+
+        let nonce = self.nonces.next(&initiator_source_address).await?;
+        let initiator = ChainAddress::from_str(&initiator_source_address)?;
+        let (secret, secret_hash) = self.gen_secret(source_chain, &initiator, nonce);
+        info!("🔑 Generated secret and hash for order");
+
+        info!("📦 Building order parameters...");
+        let order = Order {
+            source_chain: source_chain.to_string(),
+            destination_chain: destination_chain.to_string(),
+            source_asset: source_asset.to_string(),
+            destination_asset: destination_asset.to_string(),
+            initiator_source_address,
+            initiator_destination_address,
+            source_amount,
+            destination_amount,
+            fee: BigDecimal::from(params.fee),
+            nonce: BigDecimal::from(nonce),
+            min_destination_confirmations: params.min_destination_confirmations as i64,
+            timelock: params.timelock as i64,
+            secret_hash,
+            additional_data: AdditonalData {
+                deadline: order_deadline(params.deadline_minutes),
+                input_token_price,
+                output_token_price,
+                strategy_id,
+                sig: None,
+                bitcoin_optional_recipient: None,
+                instant_refund_tx_bytes: None,
+                redeem_tx_bytes: None,
+                tx_hash: None,
+            },
+        };
+        info!("✅ Order parameters built successfully");
+
+        Ok((order, secret))
+    }
+
+    // Initiate an order with custom signing
+    // Helper function to retry an async operation with exponential backoff
+    async fn retry_with_backoff<F, Fut, T>(
+        &self,
+        operation: F,
+        max_retries: usize,
+        order_id: &str,
+    ) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let mut retries = 0;
+        let mut delay = 1; // Start with 1 second delay
+
+        loop {
+            match operation().await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    if retries >= max_retries {
+                        warn!(
+                            "❌ Max retries ({}) reached for order {}: {}",
+                            max_retries, order_id, e
+                        );
+                        return Err(e);
+                    }
+
+                    retries += 1;
+                    warn!(
+                        "⚠️ Attempt {} failed for order {}: {}. Retrying in {} seconds...",
+                        retries, order_id, e, delay
+                    );
+
+                    // Sleep with exponential backoff
+                    tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+                    delay += 2;
+                }
+            }
+        }
+    }
+
+    /// Sign and submit the initiate request for an order
+    pub async fn initiate_order(
+        &self,
+        order_id: &str,
+        private_key: &str,
+        account: &StarknetAccountConfig,
+    ) -> Result<String> {
+        info!("🚀 Initiating order {}...", order_id);
+        let initiate_request = self
+            .build_initiate_request(order_id, private_key, account)
+            .await?;
+        self.submit_initiate(&initiate_request).await
+    }
+
+    /// Fetch the order and sign its initiate payload without submitting it
+    pub async fn build_initiate_request(
+        &self,
+        order_id: &str,
+        private_key: &str,
+        account: &StarknetAccountConfig,
+    ) -> Result<InitiateRequest> {
+        info!("📝 Getting order details for order {}", order_id);
+        let params = self.initiate_params(order_id).await?;
+        info!(
+            "✅ Successfully retrieved order details for order {}",
+            order_id
+        );
+        info!(
+            "🔍 Source chain: {}, is starknet: {}",
+            params.source_chain,
+            params.source_chain.starts_with("starknet")
+        );
+
+        info!("✍️ Signing initiate data for order {}", order_id);
+        let signature = sign_initiate(&params, private_key, account).await?;
+        info!("✅ Successfully signed initiate data");
+
+        // Create initiate request
+        info!("📦 Creating initiate request for order {}", order_id);
+        let initiate_request = InitiateRequest {
+            order_id: order_id.to_string(),
+            signature,
+            perform_on: PerformOn::Source,
+        };
+        info!("✅ Initiate request created successfully");
+
+        Ok(initiate_request)
+    }
+
+    /// Submit a signed initiate request to the relay
+    pub async fn submit_initiate(&self, initiate_request: &InitiateRequest) -> Result<String> {
+        let order_id = initiate_request.order_id.as_str();
+
+        // Send initiate request with retry
+        info!("📤 Sending initiate request for order {}", order_id);
+
+        // Use retry with backoff for the API call
+        self.retry_with_backoff(
+            || async {
+                let response = self.api.initiate(initiate_request).await?;
+                match response.status {
+                    Status::Ok => {
+                        if let Some(tx_hash) = response.data {
+                            info!(
+                                "✅ Successfully initiated order {} with tx hash: {}",
+                                order_id, tx_hash
+                            );
+                            Ok(tx_hash)
+                        } else {
+                            warn!("❌ No transaction hash in response for order {}", order_id);
+                            Err(eyre::eyre!("No transaction hash in response"))
+                        }
+                    }
+                    Status::Error => {
+                        let error_msg = response.error.unwrap_or_default();
+                        warn!("❌ API error for order {}: {}", order_id, error_msg);
+                        Err(eyre::eyre!("API error: {}", error_msg))
+                    }
+                }
+            },
+            3, // Max 3 retries
+            order_id,
+        )
+        .await
+    }
+
+    /// Fetch the source swap fields an initiate signature covers
+    pub async fn initiate_params(&self, order_id: &str) -> Result<InitiateParams> {
+        let order_details = self.get_order_details(order_id).await?;
+        let source_swap = order_details.result.source_swap;
+
+        Ok(InitiateParams {
+            source_chain: order_details.result.create_order.source_chain,
+            initiator: source_swap.initiator.to_string(),
+            redeemer: source_swap.redeemer.to_string(),
+            amount: source_swap.amount.to_string(),
+            timelock: source_swap.timelock,
+            secret_hash: source_swap.secret_hash,
+        })
+    }
+
+    /// Matched order details as the orderbook reports them
+    pub async fn get_order_details(&self, order_id: &str) -> Result<OrderDetails> {
+        info!("📝 Fetching order details for order {}", order_id);
+        let order_details = self.api.matched_order(order_id).await?;
+        info!(
+            "✅ Successfully parsed order details for order {}",
+            order_id
+        );
+        Ok(order_details)
+    }
+
+    /// One page of the matched orders an address initiated, from any tool or machine
+    pub async fn user_orders(
+        &self,
+        address: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<Paginated<MatchedOrder>> {
+        info!("📝 Fetching page {} of orders for user {}", page, address);
+        let response = self.api.user_orders(address, page, per_page).await?;
+        match response.status {
+            Status::Ok => response
+                .data
+                .ok_or_else(|| eyre::eyre!("No orders in user orders response")),
+            Status::Error => Err(eyre::eyre!(
+                "Failed to get orders for {}: {}",
+                address,
+                response.error.unwrap_or_default()
+            )),
+        }
+    }
+
+    /// Every matched order an address initiated, walking all pages
+    pub async fn all_user_orders(&self, address: &str, per_page: u32) -> Result<Vec<MatchedOrder>> {
+        let mut orders = Vec::new();
+        let mut page = 1;
+        loop {
+            let listing = self.user_orders(address, page, per_page).await?;
+            let last = listing.data.is_empty() || page >= listing.total_pages;
+            orders.extend(listing.data);
+            if last {
+                break;
+            }
+            page += 1;
+        }
+        info!("✅ Found {} orders for user {}", orders.len(), address);
+        Ok(orders)
+    }
+
+    /// Rebuild the secret of an order created with this service's master seed
+    ///
+    /// The secret is derived from the initiator and nonce the API stored for the
+    /// order and only returned if it hashes to the order's `secret_hash`.
+    pub async fn recover_secret(&self, order_id: &str) -> Result<String> {
+        let seed = self
+            .master_seed
+            .as_ref()
+            .ok_or_else(|| eyre::eyre!("Recovering secrets requires a master seed"))?;
+        let order = self.get_order_details(order_id).await?.result.create_order;
+        let nonce = order
+            .nonce
+            .to_u64()
+            .ok_or_else(|| eyre::eyre!("Order {} has a non-integer nonce", order_id))?;
+
+        let secret = seed.derive_secret(&order.initiator_source_address, nonce);
+        let hash = SecretScheme::for_chain(&order.source_chain).hash(&secret);
+        if hash != SecretHash::from_hex(&order.secret_hash)? {
+            return Err(eyre::eyre!(
+                "Order {} was not created from this master seed",
+                order_id
+            ));
+        }
+        info!("🔑 Recovered secret for order {}", order_id);
+        Ok(hex::encode(secret))
+    }
+
+    /// Redeem an order through the relayer of its destination chain
+    pub async fn redeem_order(&self, order_id: &str, secret: &str) -> Result<RedeemResult> {
+        let order = self.get_order_details(order_id).await?.result;
+        self.redeem_matched_order(order_id, &order, secret).await
+    }
+
+    /// Redeem an order whose matched details are already at hand
+    ///
+    /// Falls back to calling the destination HTLC directly when the relayer fails
+    /// and direct redeem keys are configured.
+    pub async fn redeem_matched_order(
+        &self,
+        order_id: &str,
+        order: &MatchedOrder,
+        secret: &str,
+    ) -> Result<RedeemResult> {
+        info!("🎁 Redeeming order {}...", order_id);
+        let redeem_request = self.build_redeem_request(order_id, secret);
+        let chain = &order.destination_swap.chain;
+
+        info!(
+            "📤 Sending redeem request for order {} on {}",
+            order_id, chain
+        );
+        let relayed = match self.api.redeem(chain, &redeem_request).await {
+            Ok(text) => relayer_tx_hash(&text),
+            Err(e) => Err(e),
+        };
+        let (tx_hash, route) = match relayed {
+            Ok(tx_hash) => (tx_hash, RedeemRoute::Relayer),
+            Err(e) => match &self.direct_redeem {
+                Some(keys) => {
+                    warn!(
+                        "⚠️ Relayer failed to redeem order {}, redeeming on chain: {}",
+                        order_id, e
+                    );
+                    let tx_hash = redeem_on_chain(&order.destination_swap, secret, keys)
+                        .await
+                        .wrap_err_with(|| format!("Relayer failed with: {}", e))?;
+                    (tx_hash, RedeemRoute::OnChain)
+                }
+                None => return Err(e),
+            },
+        };
+
+        let result = RedeemResult {
+            order_id: order_id.to_string(),
+            chain: chain.clone(),
+            tx_hash,
+            route,
+        };
+        info!("✅ Redeemed order {}: {}", order_id, result);
+        Ok(result)
+    }
+
+    /// Build the redeem request for an order without submitting it
+    pub fn build_redeem_request(&self, order_id: &str, secret: &str) -> RedeemRequest {
+        info!("📦 Creating redeem request for order {}", order_id);
+        let redeem_request = RedeemRequest {
+            order_id: order_id.to_string(),
+            secret: secret.to_string(),
+            perform_on: PerformOn::Destination,
+        };
+        info!("✅ Redeem request created successfully");
+        redeem_request
+    }
+
+    /// The HTLC call that refunds an order's source swap, without sending it
+    pub async fn build_refund(&self, order_id: &str) -> Result<HtlcRefund> {
+        let order = self.get_order_details(order_id).await?.result;
+        HtlcRefund::for_swap(&order.source_swap)
+    }
+
+    /// Refund an order whose source swap has expired, returning the transaction hash
+    pub async fn refund_order(&self, order_id: &str) -> Result<String> {
+        let order = self.get_order_details(order_id).await?.result;
+        self.refund_matched_order(order_id, &order).await
+    }
+
+    /// Refund an order whose matched details are already at hand
+    ///
+    /// Calls `refund` on the source HTLC with the direct keys, which pay the gas;
+    /// the funds go back to the swap's initiator.
+    pub async fn refund_matched_order(
+        &self,
+        order_id: &str,
+        order: &MatchedOrder,
+    ) -> Result<String> {
+        info!("↩️ Refunding order {}...", order_id);
+        let keys = self.direct_redeem.as_ref().ok_or_else(|| {
+            eyre::eyre!(
+                "Refunding order {} needs a key for {} to call the HTLC with",
+                order_id,
+                order.source_swap.chain
+            )
+        })?;
+        let tx_hash = refund_on_chain(&order.source_swap, keys).await?;
+
+        info!("✅ Successfully refunded order {} in {}", order_id, tx_hash);
+        Ok(tx_hash)
+    }
+
+    /// Check if an order is ready for redemption
+    pub async fn is_order_ready_for_redemption(&self, order_id: &str) -> Result<bool> {
+        // Get the order details
+        let order_details = self.get_order_details(order_id).await?;
+
+        // Just check if the destination swap has been initiated
+        Ok(order_details.result.destination_swap.is_initiated())
+    }
+
+    /// Current lifecycle stage of an order
+    pub async fn order_status(&self, order_id: &str) -> Result<OrderStatus> {
+        Ok(self.get_order_details(order_id).await?.result.status())
+    }
+
+    /// Check that a stored order exists, is matched and locks on the hash of its secret
+    ///
+    /// The lookup is retried on errors and timeouts, since freshly created orders
+    /// take a moment to show up; a hash mismatch is reported as is.
+    pub async fn verify_order(&self, order_id: &str, secret: &str) -> Result<OrderVerification> {
+        let secret = hex::decode(secret.trim_start_matches("0x"))
+            .wrap_err_with(|| format!("Stored secret of order {} is not valid hex", order_id))?;
+
+        let mut attempt = 1;
+        let order = loop {
+            let lookup = tokio::time::timeout(VERIFY_TIMEOUT, self.api.matched_order(order_id));
+            let error = match lookup.await {
+                Ok(Ok(details)) => break details.result,
+                Ok(Err(e)) => e,
+                Err(_) => eyre::eyre!("Timed out after {:?}", VERIFY_TIMEOUT),
+            };
+            if attempt == VERIFY_ATTEMPTS {
+                return Err(error.wrap_err(format!("Order {} not found", order_id)));
+            }
+            let jitter = rand::thread_rng().gen_range(0..=VERIFY_RETRY_DELAY.as_millis() as u64);
+            tokio::time::sleep(VERIFY_RETRY_DELAY + std::time::Duration::from_millis(jitter)).await;
+            attempt += 1;
+        };
+
+        if !order.is_matched() {
+            return Err(eyre::eyre!("Order {} is not matched yet", order_id));
+        }
+        let local = SecretScheme::for_chain(&order.create_order.source_chain).hash(&secret);
+        let remote = SecretHash::from_hex(&order.create_order.secret_hash)?;
+        if local != remote {
+            return Ok(OrderVerification::SecretHashMismatch { local, remote });
+        }
+        Ok(OrderVerification::Verified(order.status()))
+    }
+
+    /// Retry redeeming an order up to max_attempts times
+    pub async fn retry_redeem_order(
+        &self,
+        order_id: &str,
+        secret: &str,
+        max_attempts: usize,
+    ) -> Result<RedeemResult> {
+        let max_attempts = if max_attempts == 0 { 5 } else { max_attempts }; // Default to 5 attempts if not specified
+
+        for attempt in 1..=max_attempts {
+            info!(
+                "🔄 Redemption attempt {}/{} for order {}",
+                attempt, max_attempts, order_id
+            );
+
+            // First check if the order is ready for redemption
+            match self.is_order_ready_for_redemption(order_id).await {
+                Ok(true) => {
+                    // Order is ready, attempt to redeem it
+                    match self.redeem_order(order_id, secret).await {
+                        Ok(result) => {
+                            info!(
+                                "✅ Successfully redeemed order {} on attempt {}/{}",
+                                order_id, attempt, max_attempts
+                            );
+                            return Ok(result);
+                        }
+                        Err(e) => {
+                            warn!(
+                                "❌ Redemption attempt {}/{} failed for order {}: {}",
+                                attempt, max_attempts, order_id, e
+                            );
+                            if attempt == max_attempts {
+                                return Err(eyre::eyre!(
+                                    "Failed to redeem order after {} attempts: {}",
+                                    max_attempts,
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                }
+                Ok(false) => {
+                    info!(
+                        "⏳ Order {} is not yet ready for redemption (attempt {}/{})",
+                        order_id, attempt, max_attempts
+                    );
+                    if attempt == max_attempts {
+                        return Err(eyre::eyre!(
+                            "Order not ready for redemption after {} attempts",
+                            max_attempts
+                        ));
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "❌ Failed to check if order {} is ready for redemption (attempt {}/{}): {}",
+                        order_id, attempt, max_attempts, e
+                    );
+                    if attempt == max_attempts {
+                        return Err(eyre::eyre!(
+                            "Failed to check order redemption status after {} attempts: {}",
+                            max_attempts,
+                            e
+                        ));
+                    }
+                }
+            }
+
+            // Wait before the next attempt (exponential backoff)
+            let wait_time = std::time::Duration::from_secs(2u64.pow(attempt as u32));
+            info!("⏱️ Waiting {:?} before next redemption attempt", wait_time);
+            tokio::time::sleep(wait_time).await;
+        }
+
+        // This should never be reached due to the returns in the loop, but just in case
+        Err(eyre::eyre!(
+            "Failed to redeem order after {} attempts",
+            max_attempts
+        ))
+    }
+
+    /// Redeem every order stored in `data/order_secrets.json`
+    pub async fn redeem_all_orders(&self, _private_key: &str) -> Result<()> {
+        info!("🔄 Starting to redeem all stored orders...");
+
+        let order_data = file_utils::load_order_data()?;
+        if order_data.is_empty() {
+            warn!("❌ No orders found to redeem");
+            return Err(eyre::eyre!("No orders found to redeem"));
+        }
+
+        let total_orders = order_data.len();
+        info!("📊 Found {} orders to redeem", total_orders);
+        let mut successful_redemptions = 0;
+        let mut failed_redemptions = 0;
+
+        for data in order_data {
+            info!("🔄 Processing order {}...", data.order_id);
+            match self.redeem_order(&data.order_id, &data.secret).await {
+                Ok(result) => {
+                    info!(
+                        "✅ Successfully redeemed order {} with tx hash: {}",
+                        data.order_id, result.tx_hash
+                    );
+                    successful_redemptions += 1;
+                }
+                Err(e) => {
+                    warn!("❌ Failed to redeem order {}: {}", data.order_id, e);
+                    failed_redemptions += 1;
+                }
+            }
+            // Small delay between redemptions
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        info!("📊 Redemption Summary:");
+        info!("  📈 Total orders: {}", total_orders);
+        info!("  ✅ Successful: {}", successful_redemptions);
+        info!("  ❌ Failed: {}", failed_redemptions);
+        info!(
+            "  📊 Success rate: {:.2}%",
+            (successful_redemptions as f64 / total_orders as f64) * 100.0
+        );
+
+        Ok(())
+    }
+}
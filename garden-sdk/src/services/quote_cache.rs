@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+
+use crate::models::quote::Quote;
+use crate::services::quote_service::QuoteService;
+use eyre::Result;
+use tracing::info;
+
+/// Seconds before expiry at which a cached quote is refreshed
+pub const QUOTE_REFRESH_MARGIN_SECS: i64 = 5;
+
+/// A quote together with the cache version it was stored under
+#[derive(Debug, Clone)]
+pub struct VersionedQuote {
+    /// Starts at 1 and grows by one on every re-quote
+    pub version: u32,
+    pub quote: Quote,
+}
+
+/// How many orders were created from one quote version
+#[derive(Debug, Clone)]
+pub struct QuoteUsage {
+    pub quote: VersionedQuote,
+    pub orders: usize,
+}
+
+/// Keeps one quote for an order pair and amount fresh across a batch of orders
+///
+/// Cheap to clone; clones share the cached quote and usage counts.
+#[derive(Debug, Clone)]
+pub struct QuoteCache {
+    quotes: QuoteService,
+    order_pair: String,
+    amount: String,
+    exact_out: bool,
+    refresh_margin: i64,
+    current: Arc<tokio::sync::Mutex<Option<VersionedQuote>>>,
+    usage: Arc<Mutex<Vec<QuoteUsage>>>,
+}
+
+impl QuoteCache {
+    pub fn new(quotes: QuoteService, order_pair: &str, amount: &str, exact_out: bool) -> Self {
+        Self {
+            quotes,
+            order_pair: order_pair.to_string(),
+            amount: amount.to_string(),
+            exact_out,
+            refresh_margin: QUOTE_REFRESH_MARGIN_SECS,
+            current: Arc::new(tokio::sync::Mutex::new(None)),
+            usage: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Refresh quotes this many seconds before they expire
+    pub fn with_refresh_margin(mut self, secs: i64) -> Self {
+        self.refresh_margin = secs;
+        self
+    }
+
+    /// The cached quote, re-quoting first if it is missing or about to expire
+    pub async fn current(&self) -> Result<VersionedQuote> {
+        let mut current = self.current.lock().await;
+        let now = chrono::Utc::now().timestamp();
+
+        if let Some(cached) = current.as_ref() {
+            if !cached.quote.is_expired(now + self.refresh_margin) {
+                return Ok(cached.clone());
+            }
+            info!(
+                "⏰ Quote v{} expires at {}, re-quoting",
+                cached.version, cached.quote.expires_at
+            );
+        }
+
+        let quote = self
+            .quotes
+            .quote(&self.order_pair, &self.amount, self.exact_out)
+            .await?;
+        let version = current.as_ref().map_or(1, |cached| cached.version + 1);
+        let fresh = VersionedQuote { version, quote };
+        info!(
+            "💱 Using quote v{} (strategy {}, valid until {})",
+            fresh.version,
+            fresh.quote.best().strategy_id,
+            fresh.quote.expires_at
+        );
+
+        self.usage.lock().unwrap().push(QuoteUsage {
+            quote: fresh.clone(),
+            orders: 0,
+        });
+        *current = Some(fresh.clone());
+        Ok(fresh)
+    }
+
+    /// Count an order as created from the given quote version
+    pub fn record_use(&self, version: u32) {
+        let mut usage = self.usage.lock().unwrap();
+        if let Some(entry) = usage
+            .iter_mut()
+            .find(|entry| entry.quote.version == version)
+        {
+            entry.orders += 1;
+        }
+    }
+
+    /// Every quote version fetched so far with the number of orders it produced
+    pub fn usage(&self) -> Vec<QuoteUsage> {
+        self.usage.lock().unwrap().clone()
+    }
+}
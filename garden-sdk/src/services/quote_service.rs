@@ -0,0 +1,142 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::models::order::Order;
+use crate::models::quote::{
+    AttestedQuote, DEFAULT_QUOTE_VALIDITY_SECS, Quote, QuoteRequest, StrategyQuote,
+};
+use crate::services::garden_api::{GardenApi, ReqwestGardenApi};
+use bigdecimal::BigDecimal;
+use eyre::Result;
+use tracing::{debug, info, warn};
+
+#[derive(Debug, Clone)]
+pub struct QuoteService {
+    api: Arc<dyn GardenApi>,
+    validity_secs: i64,
+}
+
+impl Default for QuoteService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuoteService {
+    /// Service talking to the Garden testnet quote API
+    pub fn new() -> Self {
+        Self::with_api(Arc::new(ReqwestGardenApi::testnet()))
+    }
+
+    /// Service sending its requests through the given API implementation
+    pub fn with_api(api: Arc<dyn GardenApi>) -> Self {
+        Self {
+            api,
+            validity_secs: DEFAULT_QUOTE_VALIDITY_SECS,
+        }
+    }
+
+    /// Treat quotes as current for this many seconds after they are fetched
+    pub fn with_validity(mut self, secs: i64) -> Self {
+        self.validity_secs = secs;
+        self
+    }
+
+    /// Quote an order pair, with strategies ordered best first
+    pub async fn quote(&self, order_pair: &str, amount: &str, exact_out: bool) -> Result<Quote> {
+        info!("💱 Fetching quote for order pair: {}", order_pair);
+        info!("📊 Amount: {}, Exact Out: {}", amount, exact_out);
+
+        let request = QuoteRequest {
+            order_pair: order_pair.to_string(),
+            amount: amount.to_string(),
+            exact_out,
+        };
+        let response = self.api.quote(&request).await?;
+
+        if response.status != "Ok" {
+            warn!("❌ Quote response status is not Ok: {}", response.status);
+            return Err(eyre::eyre!("Quote response status is not Ok"));
+        }
+
+        let mut strategies: Vec<StrategyQuote> = response
+            .result
+            .quotes
+            .into_iter()
+            .map(|(strategy_id, quoted)| {
+                // The API answers with the amount on the side the request left open
+                let (source_amount, destination_amount) = if exact_out {
+                    (quoted, amount.to_string())
+                } else {
+                    (amount.to_string(), quoted)
+                };
+                StrategyQuote {
+                    strategy_id,
+                    source_amount,
+                    destination_amount,
+                }
+            })
+            .collect();
+        if strategies.is_empty() {
+            warn!("❌ No quotes found in response");
+            return Err(eyre::eyre!("No quotes found in response"));
+        }
+        // Largest destination amount first, or smallest source amount for exact out;
+        // unparsable amounts sort last
+        strategies.sort_by_cached_key(|strategy| {
+            let score = if exact_out {
+                BigDecimal::from_str(&strategy.source_amount).map(|amount| -amount)
+            } else {
+                BigDecimal::from_str(&strategy.destination_amount)
+            };
+            std::cmp::Reverse(score.ok())
+        });
+
+        let fetched_at = chrono::Utc::now().timestamp();
+        let quote = Quote {
+            order_pair: request.order_pair,
+            amount: request.amount,
+            exact_out,
+            strategies,
+            input_token_price: response.result.input_token_price,
+            output_token_price: response.result.output_token_price,
+            fetched_at,
+            expires_at: fetched_at + self.validity_secs,
+        };
+
+        info!("✅ Successfully retrieved quote:");
+        info!("  📊 Strategy ID: {}", quote.best().strategy_id);
+        info!("  💰 Input token price: {}", quote.input_token_price);
+        info!("  💰 Output token price: {}", quote.output_token_price);
+        info!("  💰 Source amount: {}", quote.best().source_amount);
+        info!(
+            "  💰 Destination amount: {}",
+            quote.best().destination_amount
+        );
+        Ok(quote)
+    }
+
+    /// Get the quote behind an order attested by the API
+    pub async fn attest(&self, order: &Order) -> Result<AttestedQuote> {
+        info!("🔍 Getting attested quote for order...");
+        debug!("order_params:{:#?}", order);
+        info!("Source Amount: {}", order.source_amount);
+        info!("Destination Amount: {}", order.destination_amount);
+
+        info!("📤 Sending attestation request...");
+        let response = self
+            .api
+            .attested_quote(&order.attestation_payload())
+            .await?;
+        info!("✅ Successfully parsed attestation response");
+
+        let data = response.result.additional_data;
+        Ok(AttestedQuote {
+            strategy_id: order.additional_data.strategy_id.clone(),
+            sig: data.sig,
+            input_token_price: data.input_token_price,
+            output_token_price: data.output_token_price,
+            bitcoin_optional_recipient: data.bitcoin_optional_recipient,
+        })
+    }
+}
@@ -0,0 +1,462 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::settings::chain_order_defaults;
+use crate::models::matched_order::{MatchedOrder, SwapData, SwapLeg};
+use crate::models::order::OrderStatus;
+use crate::models::secret::{SecretHash, SecretScheme};
+use crate::services::order_service::OrderService;
+use crate::services::timelock::ChainHeights;
+use crate::utils::file_utils;
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// Where the reconciler remembers when each order entered its current stage
+pub const DEFAULT_RECONCILE_STATE_FILE: &str = "data/reconcile_state.json";
+
+/// Something ops should look at for one order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReconcileIssue {
+    /// Listed in the order ids file but no secret is stored for it
+    MissingSecret,
+    /// Stored secret is not valid hex
+    InvalidSecret { error: String },
+    /// Stored secret does not hash to the `secret_hash` the API holds
+    SecretHashMismatch { local: String, remote: String },
+    /// In the same non-final stage for longer than the stuck threshold
+    Stuck {
+        status: OrderStatus,
+        since_secs: i64,
+    },
+    /// A locked leg expires within the timelock margin; negative once it has expired.
+    /// Blocks are exact, seconds an estimate from the chain's block time.
+    TimelockRisk {
+        leg: SwapLeg,
+        expires_in_blocks: i64,
+        expires_in_secs: i64,
+    },
+    /// The API has no matched order for the id, or could not be reached
+    Unreachable { error: String },
+}
+
+/// When an order is flagged as stuck or at risk
+#[derive(Debug, Clone, Copy)]
+pub struct ReconcileThresholds {
+    pub stuck_after: Duration,
+    pub timelock_margin: Duration,
+}
+
+impl Default for ReconcileThresholds {
+    fn default() -> Self {
+        Self {
+            stuck_after: Duration::from_secs(60 * 60),
+            timelock_margin: Duration::from_secs(30 * 60),
+        }
+    }
+}
+
+/// What the reconciler last saw of an order, persisted between runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeenOrder {
+    pub status: OrderStatus,
+    /// Unix time the order was first seen in `status`
+    pub status_since: i64,
+}
+
+impl SeenOrder {
+    /// Update what was seen of an order with its latest matched details
+    pub fn observe(previous: Option<&SeenOrder>, order: &MatchedOrder, now: i64) -> SeenOrder {
+        let status = order.status();
+        let status_since = match previous {
+            Some(seen) if seen.status == status => seen.status_since,
+            _ => now,
+        };
+        SeenOrder {
+            status,
+            status_since,
+        }
+    }
+}
+
+// Legs whose funds are still locked and whose timelock therefore matters
+fn locked_legs(order: &MatchedOrder) -> impl Iterator<Item = (SwapLeg, &SwapData)> {
+    let source = &order.source_swap;
+    let destination = &order.destination_swap;
+    // Our funds sit in the source HTLC until the destination is redeemed or we refund
+    let source_locked =
+        source.is_initiated() && !source.is_refunded() && !destination.is_redeemed();
+    // Past the destination timelock the solver can take its funds back before we redeem
+    let destination_locked =
+        destination.is_initiated() && !destination.is_redeemed() && !destination.is_refunded();
+    [
+        source_locked.then_some((SwapLeg::Source, source)),
+        destination_locked.then_some((SwapLeg::Destination, destination)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Issues of one order against its matched details
+///
+/// Timelock expiry is measured like the daemon does, from the heights `heights`
+/// has for each leg's initiation and its timelock chain. Legs with either
+/// height unknown are not checked.
+pub fn check_order(
+    order: &MatchedOrder,
+    secret: Option<&str>,
+    seen: &SeenOrder,
+    heights: &ChainHeights,
+    now: i64,
+    thresholds: &ReconcileThresholds,
+) -> Vec<ReconcileIssue> {
+    let mut issues = Vec::new();
+
+    match secret.map(|secret| hex::decode(secret.trim_start_matches("0x"))) {
+        None => issues.push(ReconcileIssue::MissingSecret),
+        Some(Err(e)) => issues.push(ReconcileIssue::InvalidSecret {
+            error: e.to_string(),
+        }),
+        Some(Ok(secret)) => {
+            let local = SecretScheme::for_chain(&order.create_order.source_chain).hash(&secret);
+            match SecretHash::from_hex(&order.create_order.secret_hash) {
+                Ok(remote) if remote == local => {}
+                _ => issues.push(ReconcileIssue::SecretHashMismatch {
+                    local: local.to_string(),
+                    remote: order.create_order.secret_hash.clone(),
+                }),
+            }
+        }
+    }
+
+    let expired_unfunded = seen.status == OrderStatus::Created
+        && order
+            .create_order
+            .additional_data
+            .deadline
+            .is_some_and(|deadline| now > deadline);
+    let in_stage = now - seen.status_since;
+    let is_final = matches!(seen.status, OrderStatus::Redeemed | OrderStatus::Refunded);
+    if !is_final && !expired_unfunded && in_stage > thresholds.stuck_after.as_secs() as i64 {
+        issues.push(ReconcileIssue::Stuck {
+            status: seen.status,
+            since_secs: in_stage,
+        });
+    }
+
+    issues.extend(
+        locked_legs(order).filter_map(|(leg, swap)| timelock_risk(leg, swap, heights, thresholds)),
+    );
+    issues
+}
+
+// Flag a leg whose timelock expires within the margin
+fn timelock_risk(
+    leg: SwapLeg,
+    swap: &SwapData,
+    heights: &ChainHeights,
+    thresholds: &ReconcileThresholds,
+) -> Option<ReconcileIssue> {
+    let blocks = heights.blocks_until_expiry(swap)?;
+    let block_time = chain_order_defaults(&swap.chain).block_time_secs;
+    let secs = (blocks as f64 * block_time).ceil() as i64;
+    (secs <= thresholds.timelock_margin.as_secs() as i64).then_some(ReconcileIssue::TimelockRisk {
+        leg,
+        expires_in_blocks: blocks,
+        expires_in_secs: secs,
+    })
+}
+
+/// An order with at least one issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileEntry {
+    pub order_id: String,
+    /// Stage reported by the API, if it knows the order
+    pub status: Option<OrderStatus>,
+    pub issues: Vec<ReconcileIssue>,
+}
+
+/// Result of one reconciliation run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileReport {
+    pub generated_at: i64,
+    /// Orders compared against the API
+    pub checked: usize,
+    /// Orders with issues, ordered by id
+    pub entries: Vec<ReconcileEntry>,
+}
+
+impl ReconcileReport {
+    /// Number of issues matching a predicate across all orders
+    pub fn count(&self, matches: impl Fn(&ReconcileIssue) -> bool) -> usize {
+        self.entries
+            .iter()
+            .flat_map(|entry| &entry.issues)
+            .filter(|issue| matches(issue))
+            .count()
+    }
+}
+
+/// Compares the local order store against the orderbook's matched orders
+///
+/// Orders are the union of the order ids file and the secrets file. Stage
+/// timestamps are kept in the state file, so "stuck" means stuck since the
+/// first run that saw the order in its current stage.
+#[derive(Debug, Clone)]
+pub struct Reconciler {
+    orders: OrderService,
+    ids_path: String,
+    secrets_path: String,
+    state_path: PathBuf,
+    thresholds: ReconcileThresholds,
+    concurrency: usize,
+    heights: Option<ChainHeights>,
+}
+
+impl Reconciler {
+    pub fn new(orders: OrderService) -> Self {
+        Self {
+            orders,
+            ids_path: "data/order_ids.json".to_string(),
+            secrets_path: "data/order_secrets.json".to_string(),
+            state_path: PathBuf::from(DEFAULT_RECONCILE_STATE_FILE),
+            thresholds: ReconcileThresholds::default(),
+            concurrency: 10,
+            heights: None,
+        }
+    }
+
+    pub fn with_ids_file(mut self, path: impl Into<String>) -> Self {
+        self.ids_path = path.into();
+        self
+    }
+
+    pub fn with_secrets_file(mut self, path: impl Into<String>) -> Self {
+        self.secrets_path = path.into();
+        self
+    }
+
+    pub fn with_state_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.state_path = path.into();
+        self
+    }
+
+    pub fn with_thresholds(mut self, thresholds: ReconcileThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Judge timelocks against these heights instead of fetching them each run
+    pub fn with_chain_heights(mut self, heights: ChainHeights) -> Self {
+        self.heights = Some(heights);
+        self
+    }
+
+    fn load_state(&self) -> Result<BTreeMap<String, SeenOrder>> {
+        if !self.state_path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let content = fs::read_to_string(&self.state_path)?;
+        serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Invalid reconcile state {}", self.state_path.display()))
+    }
+
+    fn save_state(&self, state: &BTreeMap<String, SeenOrder>) -> Result<()> {
+        if let Some(dir) = self
+            .state_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+        {
+            fs::create_dir_all(dir)?;
+        }
+        // Write then rename so a crash never leaves a truncated file behind
+        let tmp = self.state_path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(state)?)?;
+        fs::rename(&tmp, &self.state_path).wrap_err_with(|| {
+            format!(
+                "Failed to persist reconcile state to {}",
+                self.state_path.display()
+            )
+        })
+    }
+
+    /// Check every stored order once and record the stages seen
+    pub async fn run(&self) -> Result<ReconcileReport> {
+        let ids: Vec<String> = if std::path::Path::new(&self.ids_path).exists() {
+            file_utils::load_json(&self.ids_path)?
+        } else {
+            Vec::new()
+        };
+        let secrets: BTreeMap<String, String> = if std::path::Path::new(&self.secrets_path).exists()
+        {
+            file_utils::load_order_data_from(&self.secrets_path)?
+                .into_iter()
+                .map(|order| (order.order_id, order.secret))
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+        let order_ids: BTreeSet<String> = ids.into_iter().chain(secrets.keys().cloned()).collect();
+        info!(
+            "🔍 Reconciling {} orders from {} and {}",
+            order_ids.len(),
+            self.ids_path,
+            self.secrets_path
+        );
+
+        let mut state = self.load_state()?;
+        let heights = self.heights.clone().unwrap_or_default();
+        let now = chrono::Utc::now().timestamp();
+        let mut results = futures::stream::iter(order_ids.iter().cloned())
+            .map(|order_id| async move {
+                let details = self.orders.get_order_details(&order_id).await;
+                (order_id, details)
+            })
+            .buffer_unordered(self.concurrency);
+
+        let mut entries = Vec::new();
+        while let Some((order_id, details)) = results.next().await {
+            let secret = secrets.get(&order_id).map(String::as_str);
+            let (status, issues) = match details {
+                Ok(details) => {
+                    let order = details.result;
+                    for (_, swap) in locked_legs(&order) {
+                        heights.fetch_swap(swap).await;
+                    }
+                    let seen = SeenOrder::observe(state.get(&order_id), &order, now);
+                    let issues =
+                        check_order(&order, secret, &seen, &heights, now, &self.thresholds);
+                    let status = seen.status;
+                    state.insert(order_id.clone(), seen);
+                    (Some(status), issues)
+                }
+                Err(e) => {
+                    let mut issues = vec![ReconcileIssue::Unreachable {
+                        error: format!("{:#}", e),
+                    }];
+                    if secret.is_none() {
+                        issues.push(ReconcileIssue::MissingSecret);
+                    }
+                    (None, issues)
+                }
+            };
+            if !issues.is_empty() {
+                entries.push(ReconcileEntry {
+                    order_id,
+                    status,
+                    issues,
+                });
+            }
+        }
+        self.save_state(&state)?;
+
+        entries.sort_by(|a, b| a.order_id.cmp(&b.order_id));
+        Ok(ReconcileReport {
+            generated_at: now,
+            checked: order_ids.len(),
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::matched_order_fixture;
+
+    #[test]
+    fn flags_stuck_orders_near_timelock_expiry() {
+        let order = matched_order_fixture();
+        let now = 1_760_900_000;
+        let thresholds = ReconcileThresholds::default();
+
+        // The arbitrum source was initiated in L2 block 98765432, included against
+        // Sepolia L1 block 9430112, and its 7300 block timelock counts L1 blocks; the
+        // destination was initiated at Starknet block 512345 with 1250 blocks
+        let initiate_tx = order.source_swap.initiate_tx_hash.clone().unwrap();
+        let early = ChainHeights::from_heights([
+            ("ethereum_sepolia", 9_430_180),
+            ("starknet_sepolia", 512_400),
+        ])
+        .with_initiate_height(&initiate_tx, 9_430_112);
+
+        let fresh = SeenOrder::observe(None, &order, now);
+        assert_eq!(fresh.status, OrderStatus::CounterpartyInitiated);
+        assert_eq!(
+            check_order(
+                &order,
+                Some(&"00".repeat(32)),
+                &fresh,
+                &early,
+                now,
+                &thresholds
+            ),
+            vec![ReconcileIssue::SecretHashMismatch {
+                local: SecretHash::of(&[0u8; 32]).to_string(),
+                remote: order.create_order.secret_hash.clone(),
+            }]
+        );
+
+        // Two hours in the same stage, and the arbitrum leg two 12s L1 blocks from expiry
+        let seen = SeenOrder {
+            status_since: now - 7200,
+            ..fresh
+        };
+        let later = SeenOrder::observe(Some(&seen), &order, now + 60);
+        assert_eq!(later.status_since, now - 7200);
+        let late = ChainHeights::from_heights([
+            ("ethereum_sepolia", 9_430_112 + 7298),
+            ("starknet_sepolia", 512_400),
+        ])
+        .with_initiate_height(&initiate_tx, 9_430_112);
+        assert_eq!(
+            check_order(&order, None, &seen, &late, now, &thresholds),
+            vec![
+                ReconcileIssue::MissingSecret,
+                ReconcileIssue::Stuck {
+                    status: OrderStatus::CounterpartyInitiated,
+                    since_secs: 7200,
+                },
+                ReconcileIssue::TimelockRisk {
+                    leg: SwapLeg::Source,
+                    expires_in_blocks: 2,
+                    expires_in_secs: 24,
+                },
+            ]
+        );
+
+        // The L2 initiate block is never compared with L1 heights; without the L1
+        // initiate height the source leg is not judged
+        let l1_only = ChainHeights::from_heights([
+            ("ethereum_sepolia", 9_430_112 + 7298),
+            ("starknet_sepolia", 512_400),
+        ]);
+        assert_eq!(
+            check_order(&order, None, &seen, &l1_only, now, &thresholds).len(),
+            2
+        );
+
+        // Without the chain heights the timelocks cannot be judged
+        assert_eq!(
+            check_order(
+                &order,
+                None,
+                &seen,
+                &ChainHeights::default(),
+                now,
+                &thresholds
+            )
+            .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,467 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::models::matched_order::MatchedOrder;
+use crate::services::order_service::OrderService;
+use crate::services::timelock::ChainHeights;
+use crate::utils::file_utils::{self, OrderData};
+use eyre::{Result, WrapErr};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// Where the daemon keeps its progress by default
+pub const DEFAULT_DAEMON_STATE_FILE: &str = "data/daemon_state.json";
+
+/// Seconds between two passes over the watched orders
+pub const DEFAULT_POLL_INTERVAL_SECS: u64 = 30;
+
+/// Failed redeem or refund attempts after which an order is given up on
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// Longest wait between two attempts on a failing order
+pub const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60 * 60);
+
+/// Where a watched order stands from the daemon's point of view
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WatchStage {
+    /// Still waiting for the solver, confirmations or the timelock
+    #[default]
+    Watching,
+    /// Destination swap redeemed
+    Redeemed,
+    /// Source swap refunded
+    Refunded,
+    /// Deadline passed without the source swap being initiated; nothing is locked
+    Abandoned,
+    /// Redeem or refund failed on every allowed attempt and needs a manual look
+    GaveUp,
+}
+
+impl WatchStage {
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Self::Watching)
+    }
+}
+
+/// Progress on one order, persisted between passes and restarts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchedOrder {
+    pub stage: WatchStage,
+    /// Failed redeem or refund attempts so far
+    #[serde(default)]
+    pub attempts: u32,
+    /// Redeem or refund tx hash that finished the order
+    #[serde(default)]
+    pub result: Option<String>,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// Unix time before which a failed order is not tried again
+    #[serde(default)]
+    pub next_attempt_at: Option<i64>,
+    pub updated_at: i64,
+}
+
+/// Wait after the given number of failed attempts: `base` doubled for every
+/// failure after the first, capped at [`MAX_RETRY_BACKOFF`]
+pub fn retry_backoff(attempts: u32, base: Duration) -> Duration {
+    let factor = 1u32 << attempts.saturating_sub(1).min(16);
+    base.saturating_mul(factor).min(MAX_RETRY_BACKOFF)
+}
+
+/// What to do with an order on this pass
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaemonAction {
+    Wait(String),
+    Redeem,
+    Refund,
+    Finish(WatchStage),
+}
+
+/// Decide the next step for an order from its matched details
+///
+/// The source timelock runs from the height `heights` has for the swap's
+/// initiation, on the chain its HTLC counts blocks on; refunds are only tried
+/// once that chain reaches the expiry, as the HTLC rejects anything earlier.
+pub fn next_action(order: &MatchedOrder, heights: &ChainHeights, now: i64) -> DaemonAction {
+    let source = &order.source_swap;
+    let destination = &order.destination_swap;
+
+    if source.is_refunded() {
+        return DaemonAction::Finish(WatchStage::Refunded);
+    }
+    if destination.is_redeemed() {
+        return DaemonAction::Finish(WatchStage::Redeemed);
+    }
+
+    if destination.is_initiated() {
+        let required = destination
+            .required_confirmations
+            .max(order.create_order.min_destination_confirmations);
+        if destination.current_confirmations >= required {
+            return DaemonAction::Redeem;
+        }
+        if !source.is_initiated() {
+            return DaemonAction::Wait(format!(
+                "{}/{} destination confirmations",
+                destination.current_confirmations, required
+            ));
+        }
+    }
+
+    if source.is_initiated() {
+        return match heights.blocks_until_expiry(source) {
+            Some(left) if left <= 0 => DaemonAction::Refund,
+            Some(left) => DaemonAction::Wait(format!(
+                "destination not ready, source timelock ends in {} blocks",
+                left
+            )),
+            None => DaemonAction::Wait(
+                "destination not ready, source timelock expiry unknown".to_string(),
+            ),
+        };
+    }
+
+    match order.create_order.additional_data.deadline {
+        Some(deadline) if now > deadline => DaemonAction::Finish(WatchStage::Abandoned),
+        _ => DaemonAction::Wait("source swap not initiated".to_string()),
+    }
+}
+
+/// Orders redeemed, refunded or still waiting after one pass
+#[derive(Debug, Clone, Default)]
+pub struct DaemonSummary {
+    pub watched: usize,
+    pub redeemed: usize,
+    pub refunded: usize,
+    pub failed: usize,
+    /// Orders that ran out of attempts on this pass
+    pub gave_up: Vec<String>,
+}
+
+/// Long-running loop that redeems stored orders once their destination swap is
+/// confirmed and refunds the ones whose source timelock ran out
+///
+/// Orders come from the secrets file on every pass, so orders created while the
+/// daemon runs are picked up. Progress is written to the state file after every
+/// order, and finished orders are never looked at again. A failed redeem or
+/// refund is retried with a growing backoff, starting at the poll interval,
+/// until the order runs out of attempts and is given up on.
+#[derive(Debug, Clone)]
+pub struct RedeemDaemon {
+    orders: OrderService,
+    secrets_path: String,
+    state_path: PathBuf,
+    poll_interval: Duration,
+    concurrency: usize,
+    max_attempts: u32,
+}
+
+impl RedeemDaemon {
+    pub fn new(orders: OrderService) -> Self {
+        Self {
+            orders,
+            secrets_path: "data/order_secrets.json".to_string(),
+            state_path: PathBuf::from(DEFAULT_DAEMON_STATE_FILE),
+            poll_interval: Duration::from_secs(DEFAULT_POLL_INTERVAL_SECS),
+            concurrency: 5,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    /// Read order ids and secrets from this file instead of `data/order_secrets.json`
+    pub fn with_secrets_file(mut self, path: impl Into<String>) -> Self {
+        self.secrets_path = path.into();
+        self
+    }
+
+    pub fn with_state_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.state_path = path.into();
+        self
+    }
+
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Give up on an order after this many failed redeem or refund attempts
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Progress on every order seen so far
+    pub fn load_state(&self) -> Result<BTreeMap<String, WatchedOrder>> {
+        if !self.state_path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let content = fs::read_to_string(&self.state_path)?;
+        serde_json::from_str(&content)
+            .wrap_err_with(|| format!("Invalid daemon state {}", self.state_path.display()))
+    }
+
+    fn save_state(&self, state: &BTreeMap<String, WatchedOrder>) -> Result<()> {
+        if let Some(dir) = self
+            .state_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+        {
+            fs::create_dir_all(dir)?;
+        }
+        // Write then rename so a crash never leaves a truncated file behind
+        let tmp = self.state_path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(state)?)?;
+        fs::rename(&tmp, &self.state_path).wrap_err_with(|| {
+            format!(
+                "Failed to persist daemon state to {}",
+                self.state_path.display()
+            )
+        })
+    }
+
+    /// Watch orders until the process is stopped, one pass every poll interval
+    pub async fn run(&self) -> Result<()> {
+        info!(
+            "👀 Watching orders from {} every {:?}",
+            self.secrets_path, self.poll_interval
+        );
+        loop {
+            match self.run_once().await {
+                Ok(summary) => info!(
+                    "📊 Pass done: {} watched, {} redeemed, {} refunded, {} failed, {} given up",
+                    summary.watched,
+                    summary.redeemed,
+                    summary.refunded,
+                    summary.failed,
+                    summary.gave_up.len()
+                ),
+                Err(e) => warn!("❌ Daemon pass failed: {}", e),
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// One pass over every stored order that is not finished yet
+    pub async fn run_once(&self) -> Result<DaemonSummary> {
+        let secrets = file_utils::load_order_data_from(&self.secrets_path).unwrap_or_default();
+        let mut state = self.load_state()?;
+        let now = chrono::Utc::now().timestamp();
+        let pending: Vec<(OrderData, WatchedOrder)> = secrets
+            .into_iter()
+            .filter_map(|order| {
+                let watched = state.get(&order.order_id).cloned().unwrap_or_default();
+                if watched.stage.is_final() {
+                    return None;
+                }
+                if let Some(at) = watched.next_attempt_at.filter(|at| *at > now) {
+                    info!(
+                        "⏳ Order {}: retrying in {}s after {} failed attempts",
+                        order.order_id,
+                        at - now,
+                        watched.attempts
+                    );
+                    return None;
+                }
+                Some((order, watched))
+            })
+            .collect();
+
+        let mut summary = DaemonSummary {
+            watched: pending.len(),
+            ..Default::default()
+        };
+        // Fetched once per pass, on first use by an order that needs it
+        let heights = ChainHeights::default();
+        let heights = &heights;
+        let mut results = futures::stream::iter(pending)
+            .map(|(order, watched)| async move {
+                let updated = self.process(&order, watched.clone(), heights).await;
+                (order.order_id, watched, updated)
+            })
+            .buffer_unordered(self.concurrency);
+
+        while let Some((order_id, previous, updated)) = results.next().await {
+            let updated = match updated {
+                Ok(updated) => updated,
+                Err(e) => {
+                    warn!("❌ Failed to check order {}: {}", order_id, e);
+                    WatchedOrder {
+                        last_error: Some(e.to_string()),
+                        updated_at: chrono::Utc::now().timestamp(),
+                        ..previous.clone()
+                    }
+                }
+            };
+            match updated.stage {
+                WatchStage::Redeemed if previous.stage != WatchStage::Redeemed => {
+                    summary.redeemed += 1
+                }
+                WatchStage::Refunded if previous.stage != WatchStage::Refunded => {
+                    summary.refunded += 1
+                }
+                WatchStage::GaveUp if previous.stage != WatchStage::GaveUp => {
+                    summary.gave_up.push(order_id.clone())
+                }
+                _ => {}
+            }
+            if updated.last_error.is_some() {
+                summary.failed += 1;
+            }
+            state.insert(order_id, updated);
+            self.save_state(&state)?;
+        }
+        Ok(summary)
+    }
+
+    // Check one order and redeem or refund it when it is due
+    async fn process(
+        &self,
+        order: &OrderData,
+        mut watched: WatchedOrder,
+        heights: &ChainHeights,
+    ) -> Result<WatchedOrder> {
+        let details = self.orders.get_order_details(&order.order_id).await?.result;
+        let now = chrono::Utc::now().timestamp();
+        watched.updated_at = now;
+        watched.last_error = None;
+
+        // The source chain height only matters once the order cannot be redeemed
+        let mut action = next_action(&details, heights, now);
+        if matches!(action, DaemonAction::Wait(_)) && details.source_swap.is_initiated() {
+            heights.fetch_swap(&details.source_swap).await;
+            action = next_action(&details, heights, now);
+        }
+
+        match action {
+            DaemonAction::Wait(reason) => {
+                info!("⏳ Order {}: {}", order.order_id, reason);
+            }
+            DaemonAction::Finish(stage) => {
+                info!("🏁 Order {} is {:?}", order.order_id, stage);
+                watched.stage = stage;
+            }
+            DaemonAction::Redeem => {
+                match self
+                    .orders
+                    .redeem_matched_order(&order.order_id, &details, &order.secret)
+                    .await
+                {
+                    Ok(result) => {
+                        watched.stage = WatchStage::Redeemed;
+                        watched.result = Some(result.tx_hash);
+                        watched.next_attempt_at = None;
+                    }
+                    Err(e) => {
+                        warn!("❌ Failed to redeem order {}: {}", order.order_id, e);
+                        self.record_failure(&order.order_id, &mut watched, e, now);
+                    }
+                }
+            }
+            DaemonAction::Refund => match self
+                .orders
+                .refund_matched_order(&order.order_id, &details)
+                .await
+            {
+                Ok(result) => {
+                    watched.stage = WatchStage::Refunded;
+                    watched.result = Some(result);
+                    watched.next_attempt_at = None;
+                }
+                Err(e) => {
+                    warn!("❌ Failed to refund order {}: {}", order.order_id, e);
+                    self.record_failure(&order.order_id, &mut watched, e, now);
+                }
+            },
+        }
+        Ok(watched)
+    }
+
+    // Count a failed redeem or refund, then back off or give up on the order
+    fn record_failure(
+        &self,
+        order_id: &str,
+        watched: &mut WatchedOrder,
+        error: eyre::Report,
+        now: i64,
+    ) {
+        watched.attempts += 1;
+        watched.last_error = Some(error.to_string());
+        if watched.attempts >= self.max_attempts {
+            warn!(
+                "🛑 Giving up on order {} after {} failed attempts",
+                order_id, watched.attempts
+            );
+            watched.stage = WatchStage::GaveUp;
+            watched.next_attempt_at = None;
+            return;
+        }
+        let backoff = retry_backoff(watched.attempts, self.poll_interval);
+        watched.next_attempt_at = Some(now + backoff.as_secs() as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::matched_order_fixture;
+
+    #[test]
+    fn waits_for_confirmations_and_refunds_after_timelock() {
+        let now = 1_760_900_000;
+        let mut order = matched_order_fixture();
+        // The arbitrum source was initiated in L2 block 98765432 with a 7300 block
+        // timelock, which counts Sepolia L1 blocks from the L1 block it was included in
+        let initiate_tx = order.source_swap.initiate_tx_hash.clone().unwrap();
+        let early = ChainHeights::from_heights([("ethereum_sepolia", 9_430_180)])
+            .with_initiate_height(&initiate_tx, 9_430_112);
+        assert_eq!(next_action(&order, &early, now), DaemonAction::Redeem);
+
+        order.destination_swap.current_confirmations = 1;
+        assert!(matches!(
+            next_action(&order, &early, now),
+            DaemonAction::Wait(_)
+        ));
+
+        order.destination_swap.initiate_tx_hash = None;
+        assert_eq!(
+            next_action(&order, &early, now),
+            DaemonAction::Wait(
+                "destination not ready, source timelock ends in 7232 blocks".to_string()
+            )
+        );
+        // The L2 initiate block is never compared with L1 heights; without the L1
+        // initiate height the daemon waits rather than guessing
+        let l1_only = ChainHeights::from_heights([("ethereum_sepolia", 9_430_112 + 7300)]);
+        assert_eq!(
+            next_action(&order, &l1_only, now),
+            DaemonAction::Wait("destination not ready, source timelock expiry unknown".to_string())
+        );
+
+        let expired = ChainHeights::from_heights([("ethereum_sepolia", 9_430_112 + 7300)])
+            .with_initiate_height(&initiate_tx, 9_430_112);
+        assert_eq!(next_action(&order, &expired, now), DaemonAction::Refund);
+
+        order.source_swap.refund_tx_hash = Some("0xabc".to_string());
+        assert_eq!(
+            next_action(&order, &expired, now),
+            DaemonAction::Finish(WatchStage::Refunded)
+        );
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_the_cap() {
+        let base = Duration::from_secs(30);
+        assert_eq!(retry_backoff(1, base), Duration::from_secs(30));
+        assert_eq!(retry_backoff(2, base), Duration::from_secs(60));
+        assert_eq!(retry_backoff(4, base), Duration::from_secs(240));
+        assert_eq!(retry_backoff(40, base), MAX_RETRY_BACKOFF);
+    }
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use crate::models::quote::{Initiate, InitiateParams, InitiateSignaturePayload};
+use crate::models::secret::SecretHash;
+use crate::services::starknet_services::{
+    StarknetAccountConfig, StarknetAccountSigner, decode_signature, encode_signature,
+    get_starknet_signature, initiate_typed_data, resolve_starknet_chain_id, starknet_chain_id,
+};
+use alloy::{
+    hex::FromHex,
+    primitives::{Address, B256, PrimitiveSignature, U256, address},
+    signers::{Signer, local::PrivateKeySigner},
+    sol_types::{Eip712Domain, SolStruct, eip712_domain},
+};
+use eyre::{Context, Result};
+use starknet_crypto::Felt;
+
+// Outcome of checking an initiate signature against its expected signer
+#[derive(Debug, Clone)]
+pub struct SignatureCheck {
+    /// Whether the signature was produced by the expected signer
+    pub valid: bool,
+    /// EIP-712 digest or SNIP-12 message hash that was signed
+    pub digest: String,
+    /// Recovered EVM address, or the Starknet public key the signature was checked against
+    pub signer: String,
+    /// Signer we expected to find
+    pub expected: String,
+}
+
+// EIP-712 domain of the EVM HTLC that verifies initiate signatures
+pub fn evm_initiate_domain() -> Eip712Domain {
+    eip712_domain! {
+        name: "HTLC".to_string(),
+        version: "1".to_string(),
+        chain_id: 421614u64,
+        verifying_contract: address!("795Dcb58d1cd4789169D5F938Ea05E17ecEB68cA"),
+    }
+}
+
+// Build the `Initiate` struct signed for EVM sources
+pub fn evm_initiate(
+    redeemer: &str,
+    timelock: u64,
+    amount: &str,
+    secret_hash: &str,
+) -> Result<Initiate> {
+    Ok(Initiate {
+        redeemer: Address::from_hex(redeemer)
+            .wrap_err_with(|| format!("Invalid redeemer address: {}", redeemer))?,
+        timelock: U256::from(timelock),
+        amount: U256::from_str(amount).wrap_err_with(|| format!("Invalid amount: {}", amount))?,
+        secretHash: SecretHash::from_hex(secret_hash)?.to_bytes32(),
+    })
+}
+
+// EIP-712 digest the initiator signs for an EVM source swap
+pub fn evm_initiate_digest(initiate: &Initiate) -> B256 {
+    initiate.eip712_signing_hash(&evm_initiate_domain())
+}
+
+// Sign initiate params offline, picking the EVM or Starknet scheme from the source chain
+pub async fn sign_initiate(
+    params: &InitiateParams,
+    private_key: &str,
+    account: &StarknetAccountConfig,
+) -> Result<String> {
+    if params.source_chain.starts_with("starknet") {
+        let chain_id = resolve_starknet_chain_id(&params.source_chain).await?;
+        let key = Felt::from_hex(private_key).wrap_err("Invalid Starknet private key")?;
+        let signer = StarknetAccountSigner::new(key, account)?;
+        let account = Felt::from_hex(&params.initiator)
+            .wrap_err_with(|| format!("Invalid Starknet account address: {}", params.initiator))?;
+
+        let signature = get_starknet_signature(
+            &signer,
+            account,
+            chain_id,
+            &params.redeemer,
+            &params.amount,
+            params.timelock as u128,
+            &params.secret_hash,
+        )
+        .await?;
+        Ok(encode_signature(&signature))
+    } else {
+        let signer = PrivateKeySigner::from_str(private_key).wrap_err("Invalid EVM private key")?;
+        let initiate = evm_initiate(
+            &params.redeemer,
+            params.timelock,
+            &params.amount,
+            &params.secret_hash,
+        )?;
+        let signature = signer
+            .sign_typed_data(&initiate, &evm_initiate_domain())
+            .await?;
+        Ok(signature.to_string())
+    }
+}
+
+// Check an initiate signature offline, picking the EVM or Starknet scheme from the source chain
+pub fn verify_initiate_signature(payload: &InitiateSignaturePayload) -> Result<SignatureCheck> {
+    if payload.params.source_chain.starts_with("starknet") {
+        verify_starknet_signature(payload)
+    } else {
+        verify_evm_signature(payload)
+    }
+}
+
+fn verify_evm_signature(payload: &InitiateSignaturePayload) -> Result<SignatureCheck> {
+    let params = &payload.params;
+    let initiate = evm_initiate(
+        &params.redeemer,
+        params.timelock,
+        &params.amount,
+        &params.secret_hash,
+    )?;
+    let digest = evm_initiate_digest(&initiate);
+
+    let signature = PrimitiveSignature::from_str(&payload.signature)
+        .wrap_err_with(|| format!("Invalid EVM signature: {}", payload.signature))?;
+    let recovered = signature
+        .recover_address_from_prehash(&digest)
+        .wrap_err("Failed to recover signer from signature")?;
+    let expected = Address::from_hex(&params.initiator)
+        .wrap_err_with(|| format!("Invalid initiator address: {}", params.initiator))?;
+
+    Ok(SignatureCheck {
+        valid: recovered == expected,
+        digest: digest.to_string(),
+        signer: recovered.to_string(),
+        expected: expected.to_string(),
+    })
+}
+
+fn verify_starknet_signature(payload: &InitiateSignaturePayload) -> Result<SignatureCheck> {
+    let params = &payload.params;
+    let chain_id = starknet_chain_id(&params.source_chain)
+        .ok_or_else(|| eyre::eyre!("Unknown Starknet chain: {}", params.source_chain))?;
+    let public_key = payload
+        .public_key
+        .as_deref()
+        .ok_or_else(|| eyre::eyre!("A public key is required to verify Starknet signatures"))?;
+    let public_key = Felt::from_hex(public_key)
+        .wrap_err_with(|| format!("Invalid Starknet public key: {}", public_key))?;
+    let account = Felt::from_hex(&params.initiator)
+        .wrap_err_with(|| format!("Invalid Starknet account address: {}", params.initiator))?;
+
+    let typed_data = initiate_typed_data(
+        chain_id,
+        &params.redeemer,
+        &params.amount,
+        params.timelock as u128,
+        &params.secret_hash,
+    )?;
+    let message_hash = typed_data
+        .message_hash(account)
+        .wrap_err("Failed to get message hash")?;
+
+    // The owner signature always leads the array; guardian signatures follow it
+    let signature = decode_signature(&payload.signature)?;
+    let (r, s) = match signature.as_slice() {
+        [r, s, ..] => (r, s),
+        _ => {
+            return Err(eyre::eyre!(
+                "Invalid Starknet signature: {}",
+                payload.signature
+            ));
+        }
+    };
+    let valid = starknet_crypto::verify(&public_key, &message_hash, r, s)
+        .map_err(|e| eyre::eyre!("Failed to verify Starknet signature: {:?}", e))?;
+
+    Ok(SignatureCheck {
+        valid,
+        digest: format!("{:#x}", message_hash),
+        signer: format!("{:#x}", public_key),
+        expected: format!("{:#x}", account),
+    })
+}
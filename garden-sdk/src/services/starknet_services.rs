@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+use crate::config::settings::starknet_network;
+use crate::models::secret::{SecretHash, SecretScheme, u256_limbs};
+use alloy::primitives::U256;
+use eyre::Context;
+use reqwest::Url;
+use serde_json::json;
+use starknet::{
+    accounts::{ExecutionEncoding, SingleOwnerAccount},
+    core::types::TypedData,
+    providers::{JsonRpcClient, Provider, jsonrpc::HttpTransport},
+    signers::{LocalWallet, Signer, SigningKey},
+};
+use starknet_crypto::Felt;
+use tracing::info;
+
+// Chain ids fetched over RPC for networks without a known constant
+static RESOLVED_CHAIN_IDS: OnceLock<Mutex<HashMap<String, Felt>>> = OnceLock::new();
+
+// Starknet account implementations, which differ in how they lay out signatures
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StarknetAccountType {
+    /// OpenZeppelin account: `[r, s]`
+    #[default]
+    OpenZeppelin,
+    /// Argent account: `[r, s]`, followed by the guardian's `[r, s]` when it has one
+    Argent,
+    /// Braavos account: `[r, s]` from its Stark signer. Accounts that also require a
+    /// hardware (secp256r1) or multisig signer cannot be signed for with a Stark key alone.
+    Braavos,
+    /// Cairo 0 Argent account, same signature layout as `Argent`
+    ArgentCairo0,
+    /// Cairo 0 Braavos account, same signature layout as `Braavos`
+    BraavosCairo0,
+}
+
+impl StarknetAccountType {
+    // Calldata encoding the account's `__execute__` expects
+    pub fn execution_encoding(&self) -> ExecutionEncoding {
+        match self {
+            Self::OpenZeppelin | Self::Argent | Self::Braavos => ExecutionEncoding::New,
+            Self::ArgentCairo0 | Self::BraavosCairo0 => ExecutionEncoding::Legacy,
+        }
+    }
+
+    fn supports_guardian(&self) -> bool {
+        matches!(self, Self::Argent | Self::ArgentCairo0)
+    }
+}
+
+impl FromStr for StarknetAccountType {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "openzeppelin" | "oz" => Ok(Self::OpenZeppelin),
+            "argent" => Ok(Self::Argent),
+            "braavos" => Ok(Self::Braavos),
+            "argent-cairo0" => Ok(Self::ArgentCairo0),
+            "braavos-cairo0" => Ok(Self::BraavosCairo0),
+            _ => Err(eyre::eyre!(
+                "Unknown Starknet account type: {} (expected openzeppelin, argent, braavos, argent-cairo0 or braavos-cairo0)",
+                s
+            )),
+        }
+    }
+}
+
+// How the Starknet source account signs: its type and an optional guardian key
+#[derive(Debug, Clone, Default)]
+pub struct StarknetAccountConfig {
+    pub account_type: StarknetAccountType,
+    /// Hex encoded guardian private key, for Argent accounts with a guardian
+    pub guardian_key: Option<String>,
+}
+
+// Signs hashes for a Starknet account and lays the result out the way the account expects
+#[derive(Debug, Clone)]
+pub struct StarknetAccountSigner {
+    account_type: StarknetAccountType,
+    owner: LocalWallet,
+    guardian: Option<LocalWallet>,
+}
+
+impl StarknetAccountSigner {
+    pub fn new(owner_key: Felt, config: &StarknetAccountConfig) -> eyre::Result<Self> {
+        let guardian = match &config.guardian_key {
+            Some(_) if !config.account_type.supports_guardian() => {
+                return Err(eyre::eyre!(
+                    "{:?} accounts do not support a guardian key",
+                    config.account_type
+                ));
+            }
+            Some(key) => {
+                let key = Felt::from_hex(key).wrap_err("Invalid guardian private key")?;
+                Some(LocalWallet::from(SigningKey::from_secret_scalar(key)))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            account_type: config.account_type,
+            owner: LocalWallet::from(SigningKey::from_secret_scalar(owner_key)),
+            guardian,
+        })
+    }
+
+    pub fn account_type(&self) -> StarknetAccountType {
+        self.account_type
+    }
+
+    pub fn owner(&self) -> &LocalWallet {
+        &self.owner
+    }
+
+    // Sign a message hash and return the account's signature array
+    pub async fn sign_hash(&self, hash: &Felt) -> eyre::Result<Vec<Felt>> {
+        let owner = self
+            .owner
+            .sign_hash(hash)
+            .await
+            .wrap_err("Failed to sign message hash")?;
+        let mut signature = vec![owner.r, owner.s];
+
+        // Only Argent accounts take a guardian; `new` rejects one for the others
+        if let Some(guardian) = &self.guardian {
+            let guardian = guardian
+                .sign_hash(hash)
+                .await
+                .wrap_err("Failed to sign message hash with guardian")?;
+            signature.extend([guardian.r, guardian.s]);
+        }
+
+        Ok(signature)
+    }
+}
+
+// Encode a signature array as concatenated 32-byte hex words. A plain `[r, s]` encodes the
+// same way as `Signature::to_string`.
+pub fn encode_signature(signature: &[Felt]) -> String {
+    signature
+        .iter()
+        .map(|felt| hex::encode(felt.to_bytes_be()))
+        .collect()
+}
+
+// Decode a signature array from concatenated 32-byte hex words or comma separated felts
+pub fn decode_signature(signature: &str) -> eyre::Result<Vec<Felt>> {
+    let invalid = || eyre::eyre!("Invalid Starknet signature: {}", signature);
+
+    if signature.contains(',') {
+        return signature
+            .split(',')
+            .map(|felt| Felt::from_hex(felt.trim()).map_err(|_| invalid()))
+            .collect();
+    }
+
+    let hex = signature.trim_start_matches("0x");
+    if hex.is_empty() || hex.len() % 64 != 0 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+    (0..hex.len())
+        .step_by(64)
+        .map(|i| Felt::from_hex(&format!("0x{}", &hex[i..i + 64])).map_err(|_| invalid()))
+        .collect()
+}
+
+// Build the signer and account used for live account calls on a Starknet chain
+pub async fn get_signer_and_account(
+    priv_key: Felt,
+    address: Felt,
+    chain: &str,
+    account_type: StarknetAccountType,
+) -> eyre::Result<(
+    LocalWallet,
+    SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+)> {
+    let provider = starknet_provider(chain)?;
+    let signer = LocalWallet::from(SigningKey::from_secret_scalar(priv_key));
+
+    let chain_id = resolve_starknet_chain_id(chain).await?;
+    let account: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet> =
+        SingleOwnerAccount::new(
+            provider,
+            signer.clone(),
+            address,
+            chain_id,
+            account_type.execution_encoding(),
+        );
+    Ok((signer, account))
+}
+
+// Sign the `Initiate` typed data for an account. Needs no network access.
+pub async fn get_starknet_signature(
+    signer: &StarknetAccountSigner,
+    account_address: Felt,
+    chain_id: Felt,
+    redeemer: &str,
+    amount: &str,
+    timelock: u128,
+    secret_hash: &str,
+) -> eyre::Result<Vec<Felt>> {
+    let typed_data = initiate_typed_data(chain_id, redeemer, amount, timelock, secret_hash)?;
+
+    let message_hash = typed_data
+        .message_hash(account_address)
+        .wrap_err("Failed to get message hash")?;
+
+    signer.sign_hash(&message_hash).await
+}
+
+// JSON-RPC client for a Starknet chain configured in settings
+pub fn starknet_provider(chain: &str) -> eyre::Result<JsonRpcClient<HttpTransport>> {
+    let rpc_url = starknet_network(chain)
+        .and_then(|network| network.rpc_url)
+        .ok_or_else(|| eyre::eyre!("No Starknet RPC configured for chain: {}", chain))?;
+    let url = Url::parse(rpc_url).wrap_err_with(|| format!("Invalid RPC url: {}", rpc_url))?;
+    Ok(JsonRpcClient::new(HttpTransport::new(url)))
+}
+
+// Known chain id for a Starknet chain, without touching the network
+pub fn starknet_chain_id(chain: &str) -> Option<Felt> {
+    starknet_network(chain).and_then(|network| network.chain_id)
+}
+
+// Chain id for a Starknet chain: the configured constant, or a single cached RPC lookup
+pub async fn resolve_starknet_chain_id(chain: &str) -> eyre::Result<Felt> {
+    if let Some(chain_id) = starknet_chain_id(chain) {
+        return Ok(chain_id);
+    }
+
+    let cache = RESOLVED_CHAIN_IDS.get_or_init(Default::default);
+    if let Some(chain_id) = cache.lock().unwrap().get(chain) {
+        return Ok(*chain_id);
+    }
+
+    info!("🔍 Resolving chain id for {} over RPC", chain);
+    let chain_id = starknet_provider(chain)?
+        .chain_id()
+        .await
+        .wrap_err("Failed to get chain ID")?;
+    cache.lock().unwrap().insert(chain.to_string(), chain_id);
+    Ok(chain_id)
+}
+
+// Build the SNIP-12 `Initiate` typed data signed by the HTLC initiator. The amount is
+// sent as `u256` limbs and the secret hash as the HTLC's `u32` words.
+pub fn initiate_typed_data(
+    chain_id: Felt,
+    redeemer: &str,
+    amount: &str,
+    timelock: u128,
+    secret_hash: &str,
+) -> eyre::Result<TypedData> {
+    let (amount_low, amount_high) =
+        u256_limbs(U256::from_str(amount).wrap_err_with(|| format!("Invalid amount: {}", amount))?);
+    let secret_hash = SecretScheme::Starknet.encode(&SecretHash::from_hex(secret_hash)?);
+
+    let raw = json!({
+        "types": {
+            "StarknetDomain": [
+                { "name": "name", "type": "shortstring" },
+                { "name": "version", "type": "shortstring" },
+                { "name": "chainId", "type": "shortstring" },
+                { "name": "revision", "type": "shortstring" }
+            ],
+            "Initiate": [
+                { "name": "redeemer", "type": "ContractAddress" },
+                { "name": "amount", "type": "u256" },
+                { "name": "timelock", "type": "u128" },
+                { "name": "secretHash", "type": "u128*" }
+            ]
+        },
+        "primaryType": "Initiate",
+        "domain": {
+            "name": "HTLC",
+            "version": "49",
+            "chainId": chain_id.to_string(),
+            "revision": "1"
+        },
+        "message": {
+            "redeemer": redeemer,
+            "amount": { "low": amount_low.to_string(), "high": amount_high.to_string() },
+            "timelock": timelock.to_string(),
+            "secretHash": secret_hash
+        }
+    });
+
+    serde_json::from_value::<TypedData>(raw).wrap_err("Failed to parse message data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(account_type: &str, guardian_key: Option<&str>) -> StarknetAccountConfig {
+        StarknetAccountConfig {
+            account_type: account_type.parse().unwrap(),
+            guardian_key: guardian_key.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn signature_layout_follows_the_account_type() {
+        let owner = Felt::from_hex("0x1234567890abcdef").unwrap();
+        let hash = Felt::from_hex("0x2a").unwrap();
+        let owner_only = StarknetAccountSigner::new(owner, &config("openzeppelin", None))
+            .unwrap()
+            .sign_hash(&hash)
+            .await
+            .unwrap();
+        assert_eq!(owner_only.len(), 2);
+
+        for braavos in ["braavos", "braavos-cairo0"] {
+            let signer = StarknetAccountSigner::new(owner, &config(braavos, None)).unwrap();
+            assert_eq!(signer.sign_hash(&hash).await.unwrap(), owner_only);
+            assert!(StarknetAccountSigner::new(owner, &config(braavos, Some("0x5"))).is_err());
+        }
+        assert_eq!(
+            "braavos-cairo0"
+                .parse::<StarknetAccountType>()
+                .unwrap()
+                .execution_encoding(),
+            ExecutionEncoding::Legacy
+        );
+
+        let argent = StarknetAccountSigner::new(owner, &config("argent", Some("0x5")))
+            .unwrap()
+            .sign_hash(&hash)
+            .await
+            .unwrap();
+        assert_eq!(argent.len(), 4);
+        assert_eq!(argent[..2], owner_only[..]);
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use crate::config::settings::{evm_network, timelock_chain};
+use crate::models::matched_order::SwapData;
+use crate::models::secret::SecretScheme;
+use crate::services::starknet_services::starknet_provider;
+use alloy::primitives::B256;
+use alloy::providers::{Provider, ProviderBuilder, RootProvider};
+use alloy::transports::http::{Client, Http};
+use eyre::{Result, WrapErr};
+use reqwest::Url;
+use starknet::providers::Provider as _;
+use tracing::warn;
+
+/// Blocks left until a timelock expires, negative once it has expired
+///
+/// `initiated_at` and `current_height` are both heights of the chain the HTLC's
+/// `block.number` reads (see `timelock_chain`). `None` while either is unknown.
+pub fn blocks_until_expiry(
+    initiated_at: Option<u64>,
+    timelock: u64,
+    current_height: Option<u64>,
+) -> Option<i64> {
+    let expires_at = initiated_at? + timelock;
+    Some(expires_at as i64 - current_height? as i64)
+}
+
+/// Block heights of the chains timelocks count on, fetched once per chain
+///
+/// Heights are keyed by the swap chain's timelock chain, so Arbitrum swaps share
+/// the Ethereum height. The API reports a swap's `initiate_block_number` on the
+/// swap's own chain, so for Arbitrum the Ethereum height at initiation is read
+/// from the initiate receipt's `l1BlockNumber` instead. A failed fetch is logged
+/// and remembered as unknown. Cheap to clone; clones share the heights.
+#[derive(Debug, Clone, Default)]
+pub struct ChainHeights {
+    heights: Arc<Mutex<BTreeMap<String, Option<u64>>>>,
+    // Timelock chain heights at initiation, by initiate tx hash, for swaps whose
+    // timelock counts on another chain
+    initiated: Arc<Mutex<BTreeMap<String, Option<u64>>>>,
+}
+
+impl ChainHeights {
+    /// Heights known up front, keyed by timelock chain
+    pub fn from_heights<'a>(heights: impl IntoIterator<Item = (&'a str, u64)>) -> Self {
+        Self {
+            heights: Arc::new(Mutex::new(
+                heights
+                    .into_iter()
+                    .map(|(chain, height)| (chain.to_string(), Some(height)))
+                    .collect(),
+            )),
+            initiated: Default::default(),
+        }
+    }
+
+    /// Timelock chain height a swap initiated in `tx_hash` started from, known up front
+    pub fn with_initiate_height(self, tx_hash: &str, height: u64) -> Self {
+        self.initiated
+            .lock()
+            .unwrap()
+            .insert(tx_hash.to_lowercase(), Some(height));
+        self
+    }
+
+    /// Height timelocks on `chain` are measured against, if already fetched
+    pub fn known(&self, chain: &str) -> Option<u64> {
+        let heights = self.heights.lock().unwrap();
+        heights.get(timelock_chain(chain)).copied().flatten()
+    }
+
+    /// Timelock chain height the swap's timelock started from, if known
+    pub fn initiated_at(&self, swap: &SwapData) -> Option<u64> {
+        if timelock_chain(&swap.chain) == swap.chain {
+            return swap.initiate_block_number;
+        }
+        let tx_hash = swap.initiate_tx_hash.as_deref()?.to_lowercase();
+        self.initiated
+            .lock()
+            .unwrap()
+            .get(&tx_hash)
+            .copied()
+            .flatten()
+    }
+
+    /// Height timelocks on `chain` are measured against, fetching it on first use
+    pub async fn fetch(&self, chain: &str) -> Option<u64> {
+        let clock = timelock_chain(chain);
+        if let Some(height) = self.heights.lock().unwrap().get(clock) {
+            return *height;
+        }
+        let height = match block_height(clock).await {
+            Ok(height) => Some(height),
+            Err(e) => {
+                warn!("⚠️ Failed to get the block height of {}: {:#}", clock, e);
+                None
+            }
+        };
+        self.heights
+            .lock()
+            .unwrap()
+            .insert(clock.to_string(), height);
+        height
+    }
+
+    /// Fetch what is missing to judge a swap's timelock: the timelock chain's
+    /// height and, for rollup swaps, the height the swap was initiated at
+    pub async fn fetch_swap(&self, swap: &SwapData) {
+        self.fetch(&swap.chain).await;
+        let Some(tx_hash) = swap.initiate_tx_hash.as_deref() else {
+            return;
+        };
+        let tx_hash = tx_hash.to_lowercase();
+        if timelock_chain(&swap.chain) == swap.chain
+            || self.initiated.lock().unwrap().contains_key(&tx_hash)
+        {
+            return;
+        }
+        let height = match l1_block_number(&swap.chain, &tx_hash).await {
+            Ok(height) => Some(height),
+            Err(e) => {
+                warn!(
+                    "⚠️ Failed to get the L1 block of {} on {}: {:#}",
+                    tx_hash, swap.chain, e
+                );
+                None
+            }
+        };
+        self.initiated.lock().unwrap().insert(tx_hash, height);
+    }
+
+    /// Blocks left until a swap expires, from heights fetched so far
+    pub fn blocks_until_expiry(&self, swap: &SwapData) -> Option<i64> {
+        blocks_until_expiry(
+            self.initiated_at(swap),
+            swap.timelock,
+            self.known(&swap.chain),
+        )
+    }
+}
+
+// HTTP provider for an EVM chain's configured RPC
+fn evm_provider(chain: &str) -> Result<RootProvider<Http<Client>>> {
+    let rpc_url = evm_network(chain)
+        .map(|network| network.rpc_url)
+        .ok_or_else(|| eyre::eyre!("No EVM RPC configured for chain: {}", chain))?;
+    let url = Url::parse(rpc_url).wrap_err_with(|| format!("Invalid RPC url: {}", rpc_url))?;
+    Ok(ProviderBuilder::new().on_http(url))
+}
+
+// Latest block number of a chain over its configured RPC
+async fn block_height(chain: &str) -> Result<u64> {
+    match SecretScheme::for_chain(chain) {
+        SecretScheme::Evm => evm_provider(chain)?
+            .get_block_number()
+            .await
+            .wrap_err("Failed to get block number"),
+        SecretScheme::Starknet => starknet_provider(chain)?
+            .block_number()
+            .await
+            .wrap_err("Failed to get block number"),
+        SecretScheme::Bitcoin => Err(eyre::eyre!("Block heights are not supported on {}", chain)),
+    }
+}
+
+// L1 block a rollup tx was included against, from the `l1BlockNumber` Arbitrum
+// adds to its receipts; that is the height its `block.number` returns
+async fn l1_block_number(chain: &str, tx_hash: &str) -> Result<u64> {
+    let hash: B256 = tx_hash
+        .parse()
+        .wrap_err_with(|| format!("Invalid tx hash: {}", tx_hash))?;
+    let receipt: Option<serde_json::Value> = evm_provider(chain)?
+        .raw_request("eth_getTransactionReceipt".into(), (hash,))
+        .await
+        .wrap_err("Failed to get the initiate receipt")?;
+    let receipt = receipt.ok_or_else(|| eyre::eyre!("No receipt for {}", tx_hash))?;
+    let l1_block = receipt["l1BlockNumber"]
+        .as_str()
+        .ok_or_else(|| eyre::eyre!("Receipt of {} has no l1BlockNumber", tx_hash))?;
+    u64::from_str_radix(l1_block.trim_start_matches("0x"), 16)
+        .wrap_err_with(|| format!("Invalid l1BlockNumber: {}", l1_block))
+}
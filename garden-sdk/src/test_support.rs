@@ -0,0 +1,22 @@
+//! Fixtures shared by the unit tests and the integration tests in `tests/`
+
+use crate::models::matched_order::{MatchedOrder, OrderDetails};
+use crate::services::api_recorder::Exchange;
+
+/// Order id of the hand-written session's matched order
+pub const ORDER_ID: &str = "3f0c2a7e9b1d4c6a8e0f2b4d6a8c0e1f3a5b7c9d1e3f5a7b9c1d3e5f7a9b1c3d";
+
+/// Matched order exchange of the hand-written session in `tests/fixtures`
+pub fn matched_order_exchange() -> Exchange {
+    serde_json::from_str(include_str!(
+        "../tests/fixtures/handwritten_session/0003-matched_order.json"
+    ))
+    .unwrap()
+}
+
+/// Matched order of the hand-written session in `tests/fixtures`
+pub fn matched_order_fixture() -> MatchedOrder {
+    let details: OrderDetails =
+        serde_json::from_value(matched_order_exchange().response.unwrap()).unwrap();
+    details.result
+}
@@ -72,7 +72,7 @@ pub fn find_quote_by_chains(
             let source_parts: Vec<&str> = parts[0].split(":").collect();
             let dest_parts: Vec<&str> = parts[1].split(":").collect();
 
-            if source_parts.len() < 1 || dest_parts.len() < 1 {
+            if source_parts.is_empty() || dest_parts.is_empty() {
                 return false;
             }
 
@@ -89,7 +89,7 @@ pub fn list_available_chain_pairs(quotes: &DummyQuotes) -> Vec<(String, String)>
             let source_parts: Vec<&str> = parts[0].split(":").collect();
             let dest_parts: Vec<&str> = parts[1].split(":").collect();
 
-            if source_parts.len() >= 1 && dest_parts.len() >= 1 {
+            if !source_parts.is_empty() && !dest_parts.is_empty() {
                 let pair = (source_parts[0].to_string(), dest_parts[0].to_string());
                 if !pairs.contains(&pair) {
                     pairs.push(pair);
@@ -0,0 +1,114 @@
+use eyre::Result;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Write};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderData {
+    pub order_id: String,
+    pub secret: String,
+}
+
+pub fn save_order_ids(order_ids: &[String]) -> Result<()> {
+    std::fs::create_dir_all("data")?;
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open("data/order_ids.json")?;
+
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut writer, &order_ids)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+}
+
+/// Add order ids to `data/order_ids.json`, skipping ones already tracked, and
+/// return how many were new
+pub fn add_order_ids(order_ids: &[String]) -> Result<usize> {
+    let mut tracked = load_order_ids().unwrap_or_default();
+    let before = tracked.len();
+    for order_id in order_ids {
+        if !tracked.contains(order_id) {
+            tracked.push(order_id.clone());
+        }
+    }
+    let added = tracked.len() - before;
+    if added > 0 {
+        save_order_ids(&tracked)?;
+    }
+    Ok(added)
+}
+
+pub fn save_order_data(order_id: &str, secret: &str) -> Result<()> {
+    // Create data directory if it doesn't exist
+    std::fs::create_dir_all("data")?;
+
+    let mut all_orders = load_order_data().unwrap_or_default();
+    all_orders.push(OrderData {
+        order_id: order_id.to_string(),
+        secret: secret.to_string(),
+    });
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open("data/order_secrets.json")?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut writer, &all_orders)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+}
+
+pub fn load_order_data() -> Result<Vec<OrderData>> {
+    load_order_data_from("data/order_secrets.json")
+}
+
+pub fn load_order_data_from(path: &str) -> Result<Vec<OrderData>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let order_data: Vec<OrderData> = serde_json::from_reader(reader)?;
+    Ok(order_data)
+}
+
+pub fn save_order_data_to(path: &str, orders: &[OrderData]) -> Result<()> {
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut writer, orders)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+}
+
+pub fn load_order_ids() -> Result<Vec<String>> {
+    let file = File::open("data/order_ids.json")?;
+    let reader = BufReader::new(file);
+    let order_ids: Vec<String> = serde_json::from_reader(reader)?;
+    Ok(order_ids)
+}
+
+pub fn load_json<T: DeserializeOwned>(path: &str) -> Result<T> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    Ok(serde_json::from_reader(reader)?)
+}
+
+pub fn save_json<T: Serialize>(path: &str, value: &T) -> Result<()> {
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut writer, value)?;
+    writer.write_all(b"\n")?;
+
+    Ok(())
+}
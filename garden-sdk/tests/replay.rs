@@ -0,0 +1,748 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use sha2::Digest;
+
+use garden_sdk::models::address::ChainAddress;
+use garden_sdk::models::matched_order::{SwapLeg, SwapStep};
+use garden_sdk::models::order::{OrderStatus, OrderVerification};
+use garden_sdk::models::quote::{DEFAULT_QUOTE_VALIDITY_SECS, RedeemRoute};
+use garden_sdk::models::secret::SecretHash;
+use garden_sdk::services::api_recorder::{Exchange, REDACTED, RecordingGardenApi, ReplayGardenApi};
+use garden_sdk::services::master_seed::MasterSeed;
+use garden_sdk::services::nonce_manager::NonceManager;
+use garden_sdk::services::onchain_redeem::DirectRedeemKeys;
+use garden_sdk::services::order_history::{HistoryFilter, OrderHistory};
+use garden_sdk::services::quote_cache::QuoteCache;
+use garden_sdk::services::reconciler::{ReconcileIssue, Reconciler};
+use garden_sdk::services::redeem_daemon::{RedeemDaemon, WatchStage};
+use garden_sdk::services::timelock::ChainHeights;
+use garden_sdk::test_support::{ORDER_ID, matched_order_exchange, matched_order_fixture};
+use garden_sdk::{OrderService, QuoteService, ReqwestGardenApi};
+
+// Initiate tx of the matched order's arbitrum source swap
+const SOURCE_INITIATE_TX: &str =
+    "0x9d2f6b1c7e3a5d8f0b2c4e6a8d0f1b3c5e7a9d1f3b5c7e9a1d3f5b7c9e1a3d5f";
+const PAIR: &str = "arbitrum_sepolia:0x795Dcb58d1cd4789169D5F938Ea05E17ecEB68cA::starknet_sepolia:0x58ea74e863bc9a761aa20701e04b65854f5614db3eb79b2d3a76a8771694c02";
+
+// Hand-written in the recorder's file format rather than captured from testnet, so
+// they pin the replay format and our parsing, not the live API's exact responses
+fn session_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/handwritten_session")
+}
+
+fn replay_service() -> OrderService {
+    let api = ReplayGardenApi::from_fixture_dir(session_dir()).unwrap();
+    OrderService::with_api(Arc::new(api)).with_nonce_manager(NonceManager::in_memory())
+}
+
+fn fixture_exchange(file: &str) -> Exchange {
+    let content = std::fs::read_to_string(session_dir().join(file)).unwrap();
+    serde_json::from_str(&content).unwrap()
+}
+
+// Fresh directory for a test's state files, removed when dropped
+fn temp_dir(name: &str) -> tempfile::TempDir {
+    tempfile::Builder::new()
+        .prefix(&format!("garden-sdk-{}-", name))
+        .tempdir()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn replays_quote() {
+    let api = ReplayGardenApi::from_fixture_dir(session_dir()).unwrap();
+    let quote = QuoteService::with_api(Arc::new(api))
+        .quote(PAIR, "10000", false)
+        .await
+        .unwrap();
+
+    assert_eq!(quote.strategies.len(), 1);
+    assert_eq!(quote.best().strategy_id, "aa1d2c3e");
+    assert_eq!(quote.best().destination_amount, "9970");
+    assert_eq!(quote.input_token_price, 1.0);
+    assert_eq!(quote.output_token_price, 1.0);
+    assert!(quote.expires_at > quote.fetched_at);
+}
+
+#[tokio::test]
+async fn replays_attestation_for_quoted_order() {
+    let orders = replay_service();
+    let quote = orders.quotes().quote(PAIR, "10000", false).await.unwrap();
+    let (order, _secret) = orders.build_order(&quote).await.unwrap();
+    let attested = orders.quotes().attest(&order).await.unwrap();
+
+    assert_eq!(attested.strategy_id, "aa1d2c3e");
+    assert!(attested.sig.starts_with("0x"));
+}
+
+#[tokio::test]
+async fn replays_matched_order() {
+    let orders = replay_service();
+    let params = orders.initiate_params(ORDER_ID).await.unwrap();
+
+    assert_eq!(params.source_chain, "arbitrum_sepolia");
+    assert_eq!(params.amount, "10000");
+    assert_eq!(params.timelock, 7300);
+
+    // The session holds a single matched order lookup
+    assert!(orders.order_status(ORDER_ID).await.is_err());
+}
+
+#[tokio::test]
+async fn replays_order_status() {
+    let status = replay_service().order_status(ORDER_ID).await.unwrap();
+    assert_eq!(status, OrderStatus::CounterpartyInitiated);
+}
+
+#[tokio::test]
+async fn replays_freshly_created_order_with_null_fields() {
+    let mut exchange = matched_order_exchange();
+    let result = &mut exchange.response.as_mut().unwrap()["result"];
+    for swap in ["source_swap", "destination_swap"] {
+        for field in [
+            "secret",
+            "initiate_tx_hash",
+            "redeem_tx_hash",
+            "refund_tx_hash",
+            "initiate_block_number",
+            "redeem_block_number",
+            "refund_block_number",
+            "filled_amount",
+        ] {
+            result[swap][field] = serde_json::Value::Null;
+        }
+    }
+    result["create_order"]["additional_data"]["tx_hash"] = serde_json::Value::Null;
+    result["created_at"] = serde_json::json!("2026-10-19T10:00:00Z");
+
+    let api = ReplayGardenApi::new(vec![exchange.clone(), exchange.clone(), exchange]);
+    let orders = OrderService::with_api(Arc::new(api));
+
+    assert_eq!(
+        orders.order_status(ORDER_ID).await.unwrap(),
+        OrderStatus::Created
+    );
+    let params = orders.initiate_params(ORDER_ID).await.unwrap();
+    assert_eq!(
+        params.initiator,
+        "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901"
+    );
+}
+
+#[tokio::test]
+async fn records_redacted_fixtures() {
+    let tmp = temp_dir("record");
+    let dir = tmp.path();
+
+    let inner = ReplayGardenApi::from_fixture_dir(session_dir()).unwrap();
+    let recorder = RecordingGardenApi::new(Arc::new(inner))
+        .with_fixture_dir(dir)
+        .unwrap();
+    let orders = OrderService::with_api(Arc::new(recorder.clone()));
+
+    let secret = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+    orders.redeem_order(ORDER_ID, secret).await.unwrap();
+
+    // The matched order is looked up first to find the destination chain's relayer
+    let exchanges = recorder.exchanges();
+    assert_eq!(exchanges.len(), 2);
+    assert_eq!(exchanges[0].endpoint, "matched_order");
+    assert_eq!(exchanges[1].request["secret"], REDACTED);
+
+    let written = std::fs::read_to_string(dir.join("0002-redeem.json")).unwrap();
+    assert!(!written.contains(secret));
+
+    // What was recorded replays like the original session
+    let replayed =
+        OrderService::with_api(Arc::new(ReplayGardenApi::from_fixture_dir(dir).unwrap()));
+    assert!(replayed.redeem_order(ORDER_ID, secret).await.is_ok());
+}
+
+#[tokio::test]
+async fn recording_never_fails_the_call_and_redacts_errors() {
+    let secret = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    // A fixture dir that has gone away only costs the fixtures, not the redeem
+    let tmp = temp_dir("record-gone");
+    let inner = ReplayGardenApi::from_fixture_dir(session_dir()).unwrap();
+    let recorder = RecordingGardenApi::new(Arc::new(inner))
+        .with_fixture_dir(tmp.path().join("fixtures"))
+        .unwrap();
+    std::fs::remove_dir_all(tmp.path().join("fixtures")).unwrap();
+    let orders = OrderService::with_api(Arc::new(recorder.clone()));
+    assert!(orders.redeem_order(ORDER_ID, secret).await.is_ok());
+    assert_eq!(recorder.exchanges().len(), 2);
+
+    // Error messages echoing the secret are stored redacted
+    let rejected = Exchange {
+        endpoint: "redeem".to_string(),
+        request: serde_json::json!({}),
+        response: None,
+        error: Some(format!(
+            "Relayer rejected {}: {{\"secret\":\"{}\",\"status\":\"Error\"}}",
+            secret, secret
+        )),
+    };
+    let inner = ReplayGardenApi::new(vec![matched_order_exchange(), rejected]);
+    let recorder = RecordingGardenApi::new(Arc::new(inner));
+    let orders = OrderService::with_api(Arc::new(recorder.clone()));
+    assert!(orders.redeem_order(ORDER_ID, secret).await.is_err());
+    let error = recorder.exchanges()[1].error.clone().unwrap();
+    assert!(!error.contains(secret));
+    assert!(error.contains(REDACTED));
+}
+
+#[tokio::test]
+async fn quote_cache_requotes_expiring_quotes() {
+    let exchange = fixture_exchange("0001-quote.json");
+    let api = ReplayGardenApi::new(vec![exchange.clone(), exchange.clone(), exchange]);
+    let quotes = QuoteService::with_api(Arc::new(api));
+
+    let fresh = QuoteCache::new(quotes.clone(), PAIR, "10000", false);
+    let first = fresh.current().await.unwrap();
+    fresh.record_use(first.version);
+    fresh.record_use(fresh.current().await.unwrap().version);
+    assert_eq!(fresh.usage().len(), 1);
+    assert_eq!(fresh.usage()[0].orders, 2);
+
+    // A margin beyond the validity window makes every quote count as expiring
+    let expiring = QuoteCache::new(quotes, PAIR, "10000", false)
+        .with_refresh_margin(DEFAULT_QUOTE_VALIDITY_SECS);
+    assert_eq!(expiring.current().await.unwrap().version, 1);
+    assert_eq!(expiring.current().await.unwrap().version, 2);
+    assert_eq!(expiring.usage().len(), 2);
+}
+
+#[tokio::test]
+async fn create_order_aborts_beyond_max_slippage() {
+    let quote = fixture_exchange("0001-quote.json");
+    let mut attested = fixture_exchange("0002-attested_quote.json");
+    attested.response.as_mut().unwrap()["result"]["additional_data"]["input_token_price"] =
+        serde_json::json!(1.02);
+
+    let api = ReplayGardenApi::new(vec![quote, attested]);
+    let orders = OrderService::with_api(Arc::new(api))
+        .with_nonce_manager(NonceManager::in_memory())
+        .with_max_slippage_bps(100);
+    let quote = orders.quotes().quote(PAIR, "10000", false).await.unwrap();
+
+    let error = orders.create_order(&quote).await.unwrap_err();
+    assert!(error.to_string().contains("200.0 bps"), "{}", error);
+}
+
+#[tokio::test]
+async fn exact_out_quote_derives_source_amount() {
+    let exchange: Exchange = serde_json::from_value(serde_json::json!({
+        "endpoint": "quote",
+        "request": { "order_pair": PAIR, "amount": "9970", "exact_out": true },
+        "response": {
+            "status": "Ok",
+            "result": {
+                "quotes": { "slow": "10040", "fast": "10000" },
+                "input_token_price": 1.0,
+                "output_token_price": 1.0
+            }
+        }
+    }))
+    .unwrap();
+    let orders = OrderService::with_api(Arc::new(ReplayGardenApi::new(vec![exchange])))
+        .with_nonce_manager(NonceManager::in_memory());
+
+    let quote = orders.quotes().quote(PAIR, "9970", true).await.unwrap();
+    assert_eq!(quote.best().strategy_id, "fast");
+
+    let (order, _secret) = orders.build_order(&quote).await.unwrap();
+    assert_eq!(order.source_amount.to_string(), "10000");
+    assert_eq!(order.destination_amount.to_string(), "9970");
+}
+
+fn test_seed(byte: u8) -> MasterSeed {
+    MasterSeed::from_bytes(&[byte; 32]).unwrap()
+}
+
+#[tokio::test]
+async fn master_seed_derives_order_secrets() {
+    let orders = replay_service().with_master_seed(test_seed(7));
+    let quote = orders.quotes().quote(PAIR, "10000", false).await.unwrap();
+    let (first, first_secret) = orders.build_order(&quote).await.unwrap();
+    let (second, second_secret) = orders.build_order(&quote).await.unwrap();
+
+    let initiator: ChainAddress = first.initiator_source_address.parse().unwrap();
+    let nonce = first.nonce.to_string().parse().unwrap();
+    assert_eq!(
+        first_secret,
+        hex::encode(test_seed(7).derive_secret(&initiator, nonce))
+    );
+    assert_eq!(
+        first.secret_hash,
+        hex::encode(sha2::Sha256::digest(hex::decode(&first_secret).unwrap()))
+    );
+    // Every nonce yields its own secret
+    assert_ne!(first.nonce, second.nonce);
+    assert_ne!(first_secret, second_secret);
+}
+
+#[tokio::test]
+async fn recovers_secret_of_seeded_order() {
+    let mut exchange = matched_order_exchange();
+    let create_order = &mut exchange.response.as_mut().unwrap()["result"]["create_order"];
+    let initiator: ChainAddress = create_order["initiator_source_address"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    let nonce = create_order["nonce"]
+        .to_string()
+        .trim_matches('"')
+        .parse()
+        .unwrap();
+    let secret = test_seed(7).derive_secret(&initiator, nonce);
+    create_order["secret_hash"] = hex::encode(sha2::Sha256::digest(secret)).into();
+
+    let api = Arc::new(ReplayGardenApi::new(vec![exchange.clone(), exchange]));
+    let recovered = OrderService::with_api(api.clone())
+        .with_master_seed(test_seed(7))
+        .recover_secret(ORDER_ID)
+        .await
+        .unwrap();
+    assert_eq!(recovered, hex::encode(secret));
+
+    // A different seed never produces a secret for someone else's hash
+    let err = OrderService::with_api(api)
+        .with_master_seed(test_seed(8))
+        .recover_secret(ORDER_ID)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("not created from this master seed")
+    );
+}
+
+#[tokio::test]
+async fn daemon_redeems_ready_orders_once_across_restarts() {
+    let tmp = temp_dir("daemon");
+    let dir = tmp.path();
+    let secrets = dir.join("order_secrets.json");
+    std::fs::write(
+        &secrets,
+        serde_json::json!([{ "order_id": ORDER_ID, "secret": "00".repeat(32) }]).to_string(),
+    )
+    .unwrap();
+
+    let daemon = || {
+        RedeemDaemon::new(replay_service())
+            .with_secrets_file(secrets.to_str().unwrap())
+            .with_state_file(dir.join("daemon_state.json"))
+    };
+    let summary = daemon().run_once().await.unwrap();
+    assert_eq!((summary.watched, summary.redeemed), (1, 1));
+
+    let state = daemon().load_state().unwrap();
+    assert_eq!(state[ORDER_ID].stage, WatchStage::Redeemed);
+    assert!(
+        state[ORDER_ID]
+            .result
+            .as_deref()
+            .unwrap()
+            .contains("0x2b4d6f8a")
+    );
+
+    // A restarted daemon skips finished orders without calling the API
+    let summary = daemon().run_once().await.unwrap();
+    assert_eq!(summary.watched, 0);
+}
+
+#[tokio::test]
+async fn daemon_backs_off_and_gives_up_on_failing_redeems() {
+    let tmp = temp_dir("daemon-retry");
+    let dir = tmp.path();
+    let secrets = dir.join("order_secrets.json");
+    std::fs::write(
+        &secrets,
+        serde_json::json!([{ "order_id": ORDER_ID, "secret": "00".repeat(32) }]).to_string(),
+    )
+    .unwrap();
+    let failed_redeem = Exchange {
+        endpoint: "redeem".to_string(),
+        request: serde_json::json!({}),
+        response: None,
+        error: Some("Relayer unavailable".to_string()),
+    };
+    let api = Arc::new(ReplayGardenApi::new(vec![
+        matched_order_exchange(),
+        failed_redeem.clone(),
+        matched_order_exchange(),
+        failed_redeem,
+    ]));
+    let daemon = || {
+        RedeemDaemon::new(OrderService::with_api(api.clone()))
+            .with_secrets_file(secrets.to_str().unwrap())
+            .with_state_file(dir.join("daemon_state.json"))
+            .with_poll_interval(Duration::from_secs(30))
+            .with_max_attempts(2)
+    };
+
+    let summary = daemon().run_once().await.unwrap();
+    assert_eq!((summary.watched, summary.failed), (1, 1));
+    let state = daemon().load_state().unwrap();
+    assert_eq!(state[ORDER_ID].stage, WatchStage::Watching);
+    assert_eq!(state[ORDER_ID].attempts, 1);
+    assert!(state[ORDER_ID].next_attempt_at.is_some());
+
+    // Still backing off, so the order is not checked again yet
+    let summary = daemon().run_once().await.unwrap();
+    assert_eq!(summary.watched, 0);
+
+    // Once the backoff has passed, the second failure uses up the attempts
+    let mut state = daemon().load_state().unwrap();
+    state.get_mut(ORDER_ID).unwrap().next_attempt_at = Some(0);
+    std::fs::write(
+        dir.join("daemon_state.json"),
+        serde_json::to_string(&state).unwrap(),
+    )
+    .unwrap();
+    let summary = daemon().run_once().await.unwrap();
+    assert_eq!(summary.gave_up, vec![ORDER_ID.to_string()]);
+    let state = daemon().load_state().unwrap();
+    assert_eq!(state[ORDER_ID].stage, WatchStage::GaveUp);
+    assert_eq!(
+        state[ORDER_ID].last_error.as_deref(),
+        Some("Relayer unavailable")
+    );
+
+    let summary = daemon().run_once().await.unwrap();
+    assert_eq!(summary.watched, 0);
+}
+
+#[test]
+fn redeems_route_to_the_destination_chain_relayer() {
+    let api = ReqwestGardenApi::testnet();
+    assert_eq!(
+        api.relayer_url("starknet_sepolia").unwrap(),
+        "https://starknet-relayer.hashira.io"
+    );
+    assert_eq!(
+        api.relayer_url("arbitrum_sepolia").unwrap(),
+        "https://evm-relay-stage.hashira.io"
+    );
+
+    let api = api.with_relayer("arbitrum_sepolia", "http://localhost:4426");
+    assert_eq!(
+        api.relayer_url("arbitrum_sepolia").unwrap(),
+        "http://localhost:4426"
+    );
+
+    // A chain without a relayer of its own is never sent to another chain's relayer
+    let err = api.relayer_url("bitcoin_testnet").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("No redeem relayer configured for chain: bitcoin_testnet")
+    );
+}
+
+#[tokio::test]
+async fn direct_redeem_is_tried_when_the_relayer_fails() {
+    let failed_redeem = Exchange {
+        endpoint: "redeem".to_string(),
+        request: serde_json::json!({}),
+        response: None,
+        error: Some("Failed to redeem order: 502 Bad Gateway - ".to_string()),
+    };
+    let order = matched_order_fixture();
+    let api = Arc::new(ReplayGardenApi::new(vec![
+        failed_redeem.clone(),
+        failed_redeem,
+    ]));
+
+    let err = OrderService::with_api(api.clone())
+        .redeem_matched_order(ORDER_ID, &order, &"00".repeat(32))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("502 Bad Gateway"));
+
+    // The fixture reports no swap id, so the direct path fails before touching a chain
+    let keys = DirectRedeemKeys {
+        evm_private_key: Some(format!("0x{}", "01".repeat(32))),
+        starknet: None,
+    };
+    let err = OrderService::with_api(api)
+        .with_direct_redeem(keys)
+        .redeem_matched_order(ORDER_ID, &order, &"00".repeat(32))
+        .await
+        .unwrap_err();
+    let report = format!("{:?}", err);
+    assert!(report.contains("No swap id reported for the starknet_sepolia swap"));
+    assert!(report.contains("502 Bad Gateway"));
+}
+
+#[tokio::test]
+async fn redeem_reports_the_relayer_tx_hash() {
+    let result = replay_service()
+        .redeem_order(ORDER_ID, &"00".repeat(32))
+        .await
+        .unwrap();
+    assert_eq!(result.chain, "starknet_sepolia");
+    assert_eq!(result.route, RedeemRoute::Relayer);
+    assert_eq!(
+        result.tx_hash,
+        "0x2b4d6f8a0c2e4b6d8f0a2c4e6b8d0f2a4c6e8b0d2f4a6c8e0b2d4f6a8c0e2b4d"
+    );
+
+    let rejected = Exchange {
+        endpoint: "redeem".to_string(),
+        request: serde_json::json!({}),
+        response: Some(r#"{"status":"Error","error":"secret mismatch"}"#.into()),
+        error: None,
+    };
+    let err = OrderService::with_api(Arc::new(ReplayGardenApi::new(vec![rejected])))
+        .redeem_matched_order(ORDER_ID, &matched_order_fixture(), &"00".repeat(32))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("secret mismatch"));
+}
+
+#[tokio::test]
+async fn verifies_stored_secret_against_the_matched_order() {
+    let secret = "00".repeat(32);
+    let mut exchange = matched_order_exchange();
+    exchange.response.as_mut().unwrap()["result"]["create_order"]["secret_hash"] =
+        hex::encode(sha2::Sha256::digest([0u8; 32])).into();
+    let orders = OrderService::with_api(Arc::new(ReplayGardenApi::new(vec![exchange])));
+    assert_eq!(
+        orders.verify_order(ORDER_ID, &secret).await.unwrap(),
+        OrderVerification::Verified(OrderStatus::CounterpartyInitiated)
+    );
+
+    // The fixture's own hash was not made from this secret
+    match replay_service()
+        .verify_order(ORDER_ID, &secret)
+        .await
+        .unwrap()
+    {
+        OrderVerification::SecretHashMismatch { local, remote } => {
+            assert_eq!(local, SecretHash::of(&[0u8; 32]));
+            assert!(remote.to_string().starts_with("5c1dd9f9"));
+        }
+        other => panic!("expected a mismatch, got {:?}", other),
+    }
+
+    // Unknown orders are retried, then reported missing
+    let err = OrderService::with_api(Arc::new(ReplayGardenApi::new(vec![])))
+        .verify_order(ORDER_ID, &secret)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not found"));
+}
+
+#[tokio::test]
+async fn reconcile_reports_orphans_and_unknown_orders() {
+    let tmp = temp_dir("reconcile");
+    let dir = tmp.path();
+    let unknown = "f".repeat(64);
+    let ids = dir.join("order_ids.json");
+    std::fs::write(&ids, serde_json::json!([ORDER_ID, unknown]).to_string()).unwrap();
+
+    let report = Reconciler::new(replay_service())
+        .with_ids_file(ids.to_str().unwrap())
+        .with_secrets_file(dir.join("order_secrets.json").to_str().unwrap())
+        .with_state_file(dir.join("reconcile_state.json"))
+        .with_concurrency(1)
+        .with_chain_heights(
+            ChainHeights::from_heights([
+                ("ethereum_sepolia", 9_430_180),
+                ("starknet_sepolia", 512_400),
+            ])
+            .with_initiate_height(SOURCE_INITIATE_TX, 9_430_112),
+        )
+        .run()
+        .await
+        .unwrap();
+
+    assert_eq!(report.checked, 2);
+    assert_eq!(report.entries[0].order_id, ORDER_ID);
+    assert_eq!(
+        report.entries[0].status,
+        Some(OrderStatus::CounterpartyInitiated)
+    );
+    assert_eq!(
+        report.entries[0].issues,
+        vec![ReconcileIssue::MissingSecret]
+    );
+    assert_eq!(report.entries[1].order_id, unknown);
+    assert_eq!(report.entries[1].status, None);
+    assert!(matches!(
+        report.entries[1].issues[..],
+        [
+            ReconcileIssue::Unreachable { .. },
+            ReconcileIssue::MissingSecret
+        ]
+    ));
+    assert!(dir.join("reconcile_state.json").exists());
+}
+
+#[test]
+fn matched_order_timeline_lists_on_chain_steps() {
+    let mut order = matched_order_fixture();
+    let timeline = order.timeline();
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(
+        (timeline[0].leg, timeline[0].step, timeline[0].block_number),
+        (SwapLeg::Source, SwapStep::Initiated, Some(98765432))
+    );
+    assert_eq!(
+        (timeline[1].leg, timeline[1].chain.as_str()),
+        (SwapLeg::Destination, "starknet_sepolia")
+    );
+
+    order.destination_swap.redeem_tx_hash = Some("0xabc".to_string());
+    order.destination_swap.redeem_block_number = Some(512400);
+    let redeemed = &order.timeline()[2];
+    assert_eq!(
+        (redeemed.step, redeemed.tx_hash.as_str()),
+        (SwapStep::Redeemed, "0xabc")
+    );
+    assert_eq!(order.status(), OrderStatus::Redeemed);
+}
+
+#[tokio::test]
+async fn history_filters_and_pages_refreshed_orders() {
+    let tmp = temp_dir("history");
+    let dir = tmp.path();
+    let path = dir.join("order_history.json");
+
+    let mut history = OrderHistory::open(&path).unwrap();
+    let updated = history
+        .refresh(&replay_service(), [ORDER_ID.to_string()], 1)
+        .await
+        .unwrap();
+    assert_eq!(updated, 1);
+
+    // Three more orders created a day apart, the newest one for a larger amount
+    let base = 1_760_870_400;
+    for day in 1..=3 {
+        let mut order = matched_order_fixture();
+        order.create_order.extra.insert(
+            "created_at".to_string(),
+            chrono::DateTime::from_timestamp(base + day * 86_400, 0)
+                .unwrap()
+                .to_rfc3339()
+                .into(),
+        );
+        order.create_order.source_amount = (10_000 * day).into();
+        history.insert(&format!("order-{}", day), order, base);
+    }
+    history.save().unwrap();
+
+    let history = OrderHistory::open(&path).unwrap();
+    assert_eq!(history.len(), 4);
+    assert!(history.get(ORDER_ID).is_some());
+
+    let all = HistoryFilter::default();
+    let first = history.query(&all, 1, 3);
+    assert_eq!((first.total, first.total_pages), (4, 2));
+    assert_eq!(first.entries[0].0, ORDER_ID);
+    assert_eq!(first.entries[1].0, "order-3");
+    assert_eq!(history.query(&all, 2, 3).entries.len(), 1);
+
+    let filter = HistoryFilter {
+        pair: Some(PAIR.to_string()),
+        from: Some(base + 86_400),
+        to: Some(base + 2 * 86_400),
+        status: Some("counterparty_initiated".parse().unwrap()),
+        strategy: Some("aa1d2c3e".to_string()),
+        min_amount: Some(15_000.into()),
+        ..Default::default()
+    };
+    let page = history.query(&filter, 1, 10);
+    assert_eq!(page.total, 1);
+    assert_eq!(page.entries[0].0, "order-2");
+
+    let other_pair = HistoryFilter {
+        pair: Some(format!(
+            "{}::ethereum_sepolia",
+            PAIR.split("::").next().unwrap()
+        )),
+        ..Default::default()
+    };
+    assert_eq!(history.query(&other_pair, 1, 10).total, 0);
+    let refunded = HistoryFilter {
+        status: Some(OrderStatus::Refunded),
+        ..Default::default()
+    };
+    assert_eq!(history.query(&refunded, 1, 10).total, 0);
+}
+
+#[tokio::test]
+async fn lists_every_page_of_a_users_orders() {
+    let order = serde_json::to_value(matched_order_fixture()).unwrap();
+    let mut other = order.clone();
+    other["create_order"]["create_id"] = "f".repeat(64).into();
+    let page = |page: u32, data: Vec<serde_json::Value>| Exchange {
+        endpoint: "user_orders".to_string(),
+        request: serde_json::json!({ "page": page }),
+        response: Some(serde_json::json!({
+            "status": "Ok",
+            "result": {
+                "data": data,
+                "page": page,
+                "total_pages": 2,
+                "total_items": 2,
+                "per_page": 1
+            }
+        })),
+        error: None,
+    };
+    let api = ReplayGardenApi::new(vec![page(1, vec![order]), page(2, vec![other])]);
+    let initiator = "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901";
+
+    let orders = OrderService::with_api(Arc::new(api))
+        .all_user_orders(initiator, 1)
+        .await
+        .unwrap();
+    let ids: Vec<&str> = orders
+        .iter()
+        .map(|order| order.create_order.create_id.as_str())
+        .collect();
+    assert_eq!(ids, vec![ORDER_ID.to_string(), "f".repeat(64)]);
+
+    let failed = Exchange {
+        endpoint: "user_orders".to_string(),
+        request: serde_json::json!({}),
+        response: Some(serde_json::json!({ "status": "Error", "error": "invalid address" })),
+        error: None,
+    };
+    let err = OrderService::with_api(Arc::new(ReplayGardenApi::new(vec![failed])))
+        .user_orders("0x00", 1, 10)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("invalid address"));
+}
+
+#[tokio::test]
+async fn refunds_call_the_source_htlc_with_direct_keys() {
+    let order = matched_order_fixture();
+    let orders = OrderService::with_api(Arc::new(ReplayGardenApi::new(vec![])));
+    let err = orders
+        .refund_matched_order(ORDER_ID, &order)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("needs a key for arbitrum_sepolia"));
+
+    // The fixture reports no swap id, so the refund fails before touching a chain
+    let keys = DirectRedeemKeys {
+        evm_private_key: Some(format!("0x{}", "01".repeat(32))),
+        starknet: None,
+    };
+    let err = orders
+        .with_direct_redeem(keys)
+        .refund_matched_order(ORDER_ID, &order)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("No swap id reported for the arbitrum_sepolia swap")
+    );
+}
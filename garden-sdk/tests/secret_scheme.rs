@@ -0,0 +1,172 @@
+use alloy::primitives::U256;
+use garden_sdk::models::quote::{InitiateParams, InitiateSignaturePayload};
+use garden_sdk::models::secret::{SecretHash, SecretScheme, u256_limbs};
+use garden_sdk::services::signature_service::{
+    evm_initiate, sign_initiate, verify_initiate_signature,
+};
+use garden_sdk::services::starknet_services::{
+    StarknetAccountConfig, initiate_typed_data, starknet_chain_id,
+};
+use serde_json::json;
+use starknet_crypto::Felt;
+
+// SHA-256 of 32 zero bytes
+const ZERO_SECRET_HASH: &str = "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925";
+const STARKNET_ACCOUNT: &str = "0x056b3ebec13503cb1e1d9691f13fdc9b4ae7015765113345a7355add1e29d7dc";
+
+fn params(source_chain: &str, initiator: &str) -> InitiateParams {
+    InitiateParams {
+        source_chain: source_chain.to_string(),
+        initiator: initiator.to_string(),
+        redeemer: "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901".to_string(),
+        amount: "10000".to_string(),
+        timelock: 7300,
+        secret_hash: ZERO_SECRET_HASH.to_string(),
+    }
+}
+
+#[test]
+fn every_chain_hashes_the_secret_with_sha256() {
+    let schemes = [
+        SecretScheme::Evm,
+        SecretScheme::Starknet,
+        SecretScheme::Bitcoin,
+    ];
+    for scheme in schemes {
+        assert_eq!(scheme.hash(&[0u8; 32]).to_string(), ZERO_SECRET_HASH);
+    }
+
+    assert_eq!(
+        SecretScheme::for_chain("arbitrum_sepolia"),
+        SecretScheme::Evm
+    );
+    assert_eq!(
+        SecretScheme::for_chain("starknet_sepolia"),
+        SecretScheme::Starknet
+    );
+    assert_eq!(
+        SecretScheme::for_chain("bitcoin_testnet"),
+        SecretScheme::Bitcoin
+    );
+}
+
+#[test]
+fn chain_encodings_carry_the_same_bytes() {
+    let hash = SecretHash::from_hex(ZERO_SECRET_HASH).unwrap();
+    assert_eq!(
+        SecretHash::from_hex(&format!("0x{}", ZERO_SECRET_HASH)).unwrap(),
+        hash
+    );
+
+    assert_eq!(
+        SecretScheme::Evm.encode(&hash),
+        json!(format!("0x{}", ZERO_SECRET_HASH))
+    );
+    assert_eq!(SecretScheme::Bitcoin.encode(&hash), json!(ZERO_SECRET_HASH));
+
+    // Starknet words are the digest split into big-endian u32s
+    let words = hash.to_u32_words();
+    assert_eq!(words[0], 0x66687aad);
+    assert_eq!(words[7], 0x0d5f2925);
+    let rejoined: Vec<u8> = words.iter().flat_map(|word| word.to_be_bytes()).collect();
+    assert_eq!(rejoined, hash.as_bytes());
+    assert_eq!(
+        SecretScheme::Starknet.encode(&hash),
+        json!(words.map(|word| word.to_string()))
+    );
+
+    // u256 limbs recombine to the digest read as one big-endian number
+    let (low, high) = hash.to_u256_limbs();
+    assert_eq!(low, 0x089714856ee233b3902a591d0d5f2925);
+    assert_eq!(high, 0x66687aadf862bd776c8fc18b8e9f8e20);
+    assert_eq!(
+        (U256::from(high) << 128usize) | U256::from(low),
+        U256::from_be_bytes(*hash.as_bytes())
+    );
+
+    assert!(SecretHash::from_hex("0x1234").is_err());
+}
+
+#[test]
+fn evm_typed_data_uses_the_hash_as_bytes32() {
+    let hash = SecretHash::from_hex(ZERO_SECRET_HASH).unwrap();
+    let initiate = evm_initiate(
+        "0x3E53d785995bb74C0B9ba8F71D0d6a0c4d9E6901",
+        7300,
+        "10000",
+        ZERO_SECRET_HASH,
+    )
+    .unwrap();
+    assert_eq!(initiate.secretHash, hash.to_bytes32());
+}
+
+#[test]
+fn starknet_typed_data_encodes_amount_and_hash() {
+    let chain_id = starknet_chain_id("starknet_sepolia").unwrap();
+    let account = Felt::from_hex(STARKNET_ACCOUNT).unwrap();
+    let redeemer = STARKNET_ACCOUNT;
+    let message_hash = |amount: &str, secret_hash: &str| {
+        initiate_typed_data(chain_id, redeemer, amount, 7300, secret_hash)
+            .unwrap()
+            .message_hash(account)
+            .unwrap()
+    };
+
+    let hash = message_hash("10000", ZERO_SECRET_HASH);
+    assert_eq!(
+        hash,
+        message_hash("10000", &format!("0x{}", ZERO_SECRET_HASH))
+    );
+    assert_ne!(hash, message_hash("10001", ZERO_SECRET_HASH));
+    assert_ne!(
+        hash,
+        message_hash("10000", &SecretHash::of(b"other").to_string())
+    );
+
+    // Amounts beyond u128 need the high limb
+    let large = (U256::from(1u8) << 128usize) + U256::from(5u8);
+    assert_eq!(u256_limbs(large), (5, 1));
+    assert_ne!(
+        message_hash(&large.to_string(), ZERO_SECRET_HASH),
+        message_hash("5", ZERO_SECRET_HASH)
+    );
+}
+
+#[tokio::test]
+async fn signatures_verify_on_both_chains() {
+    let account = StarknetAccountConfig::default();
+
+    let evm_key = "0x0123456789012345678901234567890123456789012345678901234567890123";
+    let evm_address =
+        alloy::signers::local::PrivateKeySigner::from_bytes(&evm_key.parse().unwrap())
+            .unwrap()
+            .address()
+            .to_string();
+    let evm = params("arbitrum_sepolia", &evm_address);
+    let signature = sign_initiate(&evm, evm_key, &account).await.unwrap();
+    let check = verify_initiate_signature(&InitiateSignaturePayload {
+        order_id: None,
+        params: evm,
+        signature,
+        public_key: None,
+    })
+    .unwrap();
+    assert!(check.valid);
+
+    let starknet_key = Felt::from_hex("0x1234567890abcdef").unwrap();
+    let starknet = params("starknet_sepolia", STARKNET_ACCOUNT);
+    let signature = sign_initiate(&starknet, "0x1234567890abcdef", &account)
+        .await
+        .unwrap();
+    let check = verify_initiate_signature(&InitiateSignaturePayload {
+        order_id: None,
+        params: starknet,
+        signature,
+        public_key: Some(format!(
+            "{:#x}",
+            starknet_crypto::get_public_key(&starknet_key)
+        )),
+    })
+    .unwrap();
+    assert!(check.valid);
+}
@@ -1,10 +1,26 @@
 use clap::{Parser, Subcommand};
+use garden_sdk::models::quote::DEFAULT_QUOTE_VALIDITY_SECS;
+use garden_sdk::services::onchain_redeem::{DirectRedeemKeys, StarknetRedeemKey};
+use garden_sdk::services::starknet_services::StarknetAccountType;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about)]
 pub struct Args {
     #[command(subcommand)]
-    pub command: Commands,
+    pub command: Option<Commands>,
+
+    /// Write every API request/response pair to this directory as redacted JSON fixtures
+    #[clap(long, global = true, conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Answer API calls from fixtures recorded with --record instead of the network
+    #[clap(long, global = true)]
+    pub replay: Option<PathBuf>,
+
+    /// File holding a hex master seed to derive order secrets from instead of drawing them at random
+    #[clap(long, global = true)]
+    pub master_seed: Option<PathBuf>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -18,6 +34,58 @@ pub enum Commands {
         /// Path to save order IDs and secrets
         #[clap(short, long, default_value = "order_data.json")]
         output: String,
+
+        /// Source chain of the pair in data/dummy_orders.json (defaults to the first pair)
+        #[clap(long, requires = "destination_chain")]
+        source_chain: Option<String>,
+
+        /// Destination chain of the pair in data/dummy_orders.json
+        #[clap(long, requires = "source_chain")]
+        destination_chain: Option<String>,
+
+        /// Amount to quote, overriding the pair's amount in data/dummy_orders.json
+        #[clap(long)]
+        amount: Option<String>,
+
+        /// Treat --amount as the exact destination amount and derive the source amount from the quote
+        #[clap(long, requires = "amount")]
+        exact_out: bool,
+
+        /// Source HTLC timelock in source chain blocks (defaults per chain)
+        #[clap(long)]
+        timelock: Option<u64>,
+
+        /// Order fee (defaults per chain)
+        #[clap(long)]
+        fee: Option<u64>,
+
+        /// Confirmations the solver waits for on the destination chain (defaults per chain)
+        #[clap(long)]
+        min_confirmations: Option<u64>,
+
+        /// Minutes an attested order stays valid (defaults per chain)
+        #[clap(long)]
+        deadline_minutes: Option<u64>,
+
+        /// JSON file with the parameter ranges the relay accepts, by chain
+        #[clap(long)]
+        param_limits: Option<String>,
+
+        /// Abort an order when attested prices move more than this many basis points from the quote
+        #[clap(long)]
+        max_slippage_bps: Option<u32>,
+
+        /// Seconds a quote is reused across orders before re-quoting
+        #[clap(long, default_value_t = DEFAULT_QUOTE_VALIDITY_SECS)]
+        quote_validity_secs: i64,
+
+        /// Print the payloads that would be sent instead of submitting them
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Write dry-run payloads to this directory instead of printing them
+        #[clap(long, requires = "dry_run")]
+        dry_run_dir: Option<String>,
     },
 
     /// Verify created orders
@@ -32,18 +100,39 @@ pub enum Commands {
     },
 
     /// Initiate orders with EIP-712 signing
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Initiate {
+        /// Run one step of the offline signing workflow instead
+        #[command(subcommand)]
+        step: Option<InitiateStep>,
+
         /// Path to order data file
         #[clap(short, long, default_value = "order_data.json")]
         input: String,
 
         /// Private key for signing (hex format)
-        #[clap(short, long)]
-        private_key: String,
+        #[clap(short, long, required = true)]
+        private_key: Option<String>,
+
+        /// Starknet account type: openzeppelin, argent, braavos, argent-cairo0 or braavos-cairo0
+        #[clap(long, default_value = "openzeppelin")]
+        account_type: StarknetAccountType,
+
+        /// Guardian private key (hex format) for Argent accounts with a guardian
+        #[clap(long)]
+        guardian_key: Option<String>,
 
         /// Maximum concurrent initiation requests
         #[clap(short, long, default_value = "5")]
         concurrency: usize,
+
+        /// Print the payloads that would be sent instead of submitting them
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Write dry-run payloads to this directory instead of printing them
+        #[clap(long, requires = "dry_run")]
+        dry_run_dir: Option<String>,
     },
 
     /// Redeem orders
@@ -52,9 +141,223 @@ pub enum Commands {
         #[clap(short, long, default_value = "order_data.json")]
         input: String,
 
+        #[command(flatten)]
+        direct: DirectRedeemArgs,
+
         /// Maximum concurrent redemption requests
         #[clap(short, long, default_value = "5")]
         concurrency: usize,
+
+        /// Print the payloads that would be sent instead of submitting them
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Write dry-run payloads to this directory instead of printing them
+        #[clap(long, requires = "dry_run")]
+        dry_run_dir: Option<String>,
+    },
+
+    /// Refund expired orders by calling the source HTLC
+    Refund {
+        /// Path to order data file
+        #[clap(short, long, default_value = "order_data.json")]
+        input: String,
+
+        #[command(flatten)]
+        direct: DirectRedeemArgs,
+
+        /// Maximum concurrent refunds
+        #[clap(short, long, default_value = "5")]
+        concurrency: usize,
+
+        /// Print the payloads that would be sent instead of submitting them
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Write dry-run payloads to this directory instead of printing them
+        #[clap(long, requires = "dry_run")]
+        dry_run_dir: Option<String>,
+    },
+
+    /// Keep watching stored orders, redeeming them when ready and refunding expired ones
+    Daemon {
+        /// Path to the order IDs and secrets to watch, re-read on every pass
+        #[clap(short, long, default_value = "data/order_secrets.json")]
+        input: String,
+
+        /// Path to persist progress to, so a restart picks up where it stopped
+        #[clap(long, default_value = "data/daemon_state.json")]
+        state: String,
+
+        /// Seconds between passes over the watched orders
+        #[clap(long, default_value = "30")]
+        interval: u64,
+
+        /// Failed redeem or refund attempts before an order is given up on
+        #[clap(long, default_value = "5")]
+        max_attempts: u32,
+
+        /// Maximum concurrent order checks
+        #[clap(short, long, default_value = "5")]
+        concurrency: usize,
+
+        /// Make a single pass and exit
+        #[clap(long)]
+        once: bool,
+
+        #[command(flatten)]
+        direct: DirectRedeemArgs,
+    },
+
+    /// Rebuild the secrets of orders created with --master-seed
+    RecoverSecrets {
+        /// JSON list of order IDs to recover
+        #[clap(short, long, default_value = "data/order_ids.json")]
+        input: String,
+
+        /// Path to save the recovered order IDs and secrets
+        #[clap(short, long, default_value = "order_data.json")]
+        output: String,
+
+        /// Maximum concurrent order detail requests
+        #[clap(short, long, default_value = "5")]
+        concurrency: usize,
+    },
+
+    /// Compare stored orders with the API and report ones that need attention
+    Reconcile {
+        /// JSON list of order IDs
+        #[clap(long, default_value = "data/order_ids.json")]
+        ids: String,
+
+        /// Path to the stored order IDs and secrets
+        #[clap(long, default_value = "data/order_secrets.json")]
+        secrets: String,
+
+        /// Path to remember when each order entered its current stage
+        #[clap(long, default_value = "data/reconcile_state.json")]
+        state: String,
+
+        /// Minutes in one stage after which an order counts as stuck
+        #[clap(long, default_value = "60")]
+        stuck_minutes: u64,
+
+        /// Flag locked legs whose timelock ends within this many minutes
+        #[clap(long, default_value = "30")]
+        timelock_margin_minutes: u64,
+
+        /// Path to also write the report to as JSON
+        #[clap(short, long)]
+        output: Option<String>,
+
+        /// Maximum concurrent order detail requests
+        #[clap(short, long, default_value = "10")]
+        concurrency: usize,
+    },
+
+    /// Browse stored orders, or show the swap timeline of one
+    History {
+        /// Show the full detail and timeline of this order instead of a list
+        #[clap(short, long)]
+        order_id: Option<String>,
+
+        /// Only orders of this pair: `source::destination`, each side a chain or `chain:asset`
+        #[clap(long)]
+        pair: Option<String>,
+
+        /// Only orders created on or after this date (YYYY-MM-DD or RFC 3339)
+        #[clap(long)]
+        from: Option<String>,
+
+        /// Only orders created on or before this date (YYYY-MM-DD or RFC 3339)
+        #[clap(long)]
+        to: Option<String>,
+
+        /// Only orders in this stage: created, initiated, counterparty_initiated, redeemed or refunded
+        #[clap(long)]
+        status: Option<String>,
+
+        /// Only orders priced with this strategy ID
+        #[clap(long)]
+        strategy: Option<String>,
+
+        /// Minimum source amount, in the asset's smallest unit
+        #[clap(long)]
+        min_amount: Option<String>,
+
+        /// Maximum source amount, in the asset's smallest unit
+        #[clap(long)]
+        max_amount: Option<String>,
+
+        /// Page to show, starting at 1
+        #[clap(long, default_value = "1")]
+        page: usize,
+
+        /// Orders per page
+        #[clap(long, default_value = "20")]
+        page_size: usize,
+
+        /// Fetch the latest details of every stored order from the API first
+        #[clap(long)]
+        refresh: bool,
+
+        /// Path to the local order history
+        #[clap(long, default_value = "data/order_history.json")]
+        history: String,
+
+        /// Maximum concurrent order detail requests when refreshing
+        #[clap(short, long, default_value = "10")]
+        concurrency: usize,
+    },
+
+    /// Export stored orders as a ledger for accounting
+    Export {
+        /// File to write; the format follows the extension, .csv or .parquet
+        #[clap(short, long, default_value = "ledger.csv")]
+        output: String,
+
+        /// Only orders created on or after this date (YYYY-MM-DD or RFC 3339)
+        #[clap(long)]
+        from: Option<String>,
+
+        /// Only orders created on or before this date (YYYY-MM-DD or RFC 3339)
+        #[clap(long)]
+        to: Option<String>,
+
+        /// Fetch the latest details of every stored order from the API first
+        #[clap(long)]
+        refresh: bool,
+
+        /// Path to the local order history
+        #[clap(long, default_value = "data/order_history.json")]
+        history: String,
+
+        /// Maximum concurrent order detail requests when refreshing
+        #[clap(short, long, default_value = "10")]
+        concurrency: usize,
+    },
+
+    /// List the orders an address initiated, as the orderbook knows them
+    UserOrders {
+        /// Initiator address to list orders for
+        #[clap(short, long)]
+        address: String,
+
+        /// Page to show, starting at 1
+        #[clap(long, default_value = "1")]
+        page: u32,
+
+        /// Orders per page requested from the orderbook
+        #[clap(long, default_value = "50")]
+        per_page: u32,
+
+        /// Fetch every page and track the orders locally in data/order_ids.json and the history
+        #[clap(long)]
+        import: bool,
+
+        /// Path to the local order history to import into
+        #[clap(long, default_value = "data/order_history.json")]
+        history: String,
     },
 
     /// Check order status
@@ -64,10 +367,117 @@ pub enum Commands {
         order_id: String,
     },
 
-    /// Execute complete Garden Finance flow
-    GardenFlow {
-        /// Number of orders to process
-        #[clap(short, long, default_value = "1")]
-        count: usize,
+    /// Verify an initiate signature offline
+    VerifySig {
+        /// Order ID to rebuild the signed payload from
+        #[clap(short, long, conflicts_with = "payload", requires = "signature")]
+        order_id: Option<String>,
+
+        /// Signature to check against the order
+        #[clap(short, long)]
+        signature: Option<String>,
+
+        /// Raw JSON payload, or a path to a file containing it
+        #[clap(short, long, required_unless_present = "order_id")]
+        payload: Option<String>,
+
+        /// Starknet account public key (hex), required for Starknet sources
+        #[clap(long)]
+        public_key: Option<String>,
+    },
+}
+
+/// Keys for calling HTLCs on chain: redeems when the relayer fails, and refunds
+#[derive(clap::Args, Debug, Clone)]
+pub struct DirectRedeemArgs {
+    /// Private key (hex format) paying gas for direct redeems and refunds on EVM chains
+    #[clap(long)]
+    pub evm_private_key: Option<String>,
+
+    /// Private key (hex format) of the Starknet account sending direct redeems and refunds
+    #[clap(long, requires = "starknet_account")]
+    pub starknet_private_key: Option<String>,
+
+    /// Address of the Starknet account sending direct redeems and refunds
+    #[clap(long, requires = "starknet_private_key")]
+    pub starknet_account: Option<String>,
+
+    /// Starknet account type: openzeppelin, argent, braavos, argent-cairo0 or braavos-cairo0
+    #[clap(long, default_value = "openzeppelin")]
+    pub starknet_account_type: StarknetAccountType,
+}
+
+impl DirectRedeemArgs {
+    // Direct redeem keys, or `None` when the relayer is the only path
+    pub fn keys(self) -> Option<DirectRedeemKeys> {
+        let starknet =
+            self.starknet_private_key
+                .zip(self.starknet_account)
+                .map(|(private_key, account)| StarknetRedeemKey {
+                    private_key,
+                    account,
+                    account_type: self.starknet_account_type,
+                });
+        let keys = DirectRedeemKeys {
+            evm_private_key: self.evm_private_key,
+            starknet,
+        };
+        (!keys.is_empty()).then_some(keys)
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum InitiateStep {
+    /// Fetch order details and write an unsigned initiate bundle
+    Prepare {
+        /// Path to order data file
+        #[clap(short, long, default_value = "order_data.json")]
+        input: String,
+
+        /// Path to write the unsigned bundle to
+        #[clap(short, long, default_value = "initiate_bundle.json")]
+        output: String,
+
+        /// Maximum concurrent order detail requests
+        #[clap(short, long, default_value = "5")]
+        concurrency: usize,
+    },
+
+    /// Sign an initiate bundle offline
+    Sign {
+        /// Path to the unsigned bundle
+        #[clap(short, long, default_value = "initiate_bundle.json")]
+        input: String,
+
+        /// Path to write the signed bundle to
+        #[clap(short, long, default_value = "initiate_signed.json")]
+        output: String,
+
+        /// Private key for signing (hex format)
+        #[clap(short, long, required_unless_present = "keystore")]
+        private_key: Option<String>,
+
+        /// Encrypted JSON keystore holding the signing key
+        #[clap(short, long, conflicts_with = "private_key")]
+        keystore: Option<String>,
+
+        /// Starknet account type: openzeppelin, argent, braavos, argent-cairo0 or braavos-cairo0
+        #[clap(long, default_value = "openzeppelin")]
+        account_type: StarknetAccountType,
+
+        /// Guardian private key (hex format) for Argent accounts with a guardian
+        #[clap(long)]
+        guardian_key: Option<String>,
+    },
+
+    /// Submit the signed initiate requests from a bundle
+    Submit {
+        /// Path to the signed bundle
+        #[clap(short, long, default_value = "initiate_signed.json")]
+        input: String,
+
+        /// Maximum concurrent initiation requests
+        #[clap(short, long, default_value = "5")]
+        concurrency: usize,
     },
 }
@@ -0,0 +1,1208 @@
+use alloy::signers::local::PrivateKeySigner;
+use console::style;
+use dialoguer::Password;
+use eyre::{Context, Result};
+use futures::{StreamExt, stream::FuturesUnordered};
+use garden_sdk::models::order::OrderVerification;
+use garden_sdk::models::order_params::{OrderParamOverrides, OrderParams, ParamLimits};
+use garden_sdk::models::quote::{InitiateBundle, InitiateBundleEntry, InitiateSignaturePayload};
+use garden_sdk::services::api_recorder::{RecordingGardenApi, ReplayGardenApi};
+use garden_sdk::services::garden_api::{GardenApi, ReqwestGardenApi};
+use garden_sdk::services::ledger_export::{LedgerFormat, LedgerRow, write_ledger};
+use garden_sdk::services::master_seed::MasterSeed;
+use garden_sdk::services::nonce_manager::NonceManager;
+use garden_sdk::services::onchain_redeem::DirectRedeemKeys;
+use garden_sdk::services::order_history::{HistoryEntry, HistoryFilter, OrderHistory};
+use garden_sdk::services::order_service::OrderService;
+use garden_sdk::services::quote_cache::QuoteCache;
+use garden_sdk::services::reconciler::{ReconcileIssue, ReconcileThresholds, Reconciler};
+use garden_sdk::services::redeem_daemon::RedeemDaemon;
+use garden_sdk::services::signature_service::{sign_initiate, verify_initiate_signature};
+use garden_sdk::services::starknet_services::StarknetAccountConfig;
+use garden_sdk::utils::dummy_orders::{find_quote_by_chains, load_dummy_orders};
+use garden_sdk::utils::file_utils::{self, OrderData};
+use serde::Serialize;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::Semaphore;
+
+// Transport picked from --record / --replay, shared by every command
+static GARDEN_API: OnceLock<Arc<dyn GardenApi>> = OnceLock::new();
+
+// Talk to the live API, record fixtures while doing so, or replay recorded ones
+pub fn init_garden_api(record: Option<PathBuf>, replay: Option<PathBuf>) -> Result<()> {
+    let api: Arc<dyn GardenApi> = match (record, replay) {
+        (_, Some(dir)) => Arc::new(ReplayGardenApi::from_fixture_dir(dir)?),
+        (Some(dir), None) => Arc::new(
+            RecordingGardenApi::new(Arc::new(ReqwestGardenApi::testnet())).with_fixture_dir(dir)?,
+        ),
+        (None, None) => Arc::new(ReqwestGardenApi::testnet()),
+    };
+    let _ = GARDEN_API.set(api);
+    Ok(())
+}
+
+// Seed from --master-seed that order secrets are derived from, if any
+static MASTER_SEED: OnceLock<MasterSeed> = OnceLock::new();
+
+pub fn init_master_seed(path: Option<PathBuf>) -> Result<()> {
+    if let Some(path) = path {
+        let _ = MASTER_SEED.set(MasterSeed::from_file(path)?);
+    }
+    Ok(())
+}
+
+// One nonce store per process, so concurrent orders never race on data/nonces.json
+static NONCES: OnceLock<NonceManager> = OnceLock::new();
+
+// Order service bound to the transport chosen for this run
+pub fn order_service() -> OrderService {
+    let service = GARDEN_API
+        .get()
+        .map(|api| OrderService::with_api(api.clone()))
+        .unwrap_or_default()
+        .with_nonce_manager(NONCES.get_or_init(NonceManager::default).clone());
+    match MASTER_SEED.get() {
+        Some(seed) => service.with_master_seed(seed.clone()),
+        None => service,
+    }
+}
+
+// Let the service call HTLCs on chain, for refunds and failed relayer redeems, if keys were given
+fn with_direct_redeem(service: OrderService, keys: Option<DirectRedeemKeys>) -> OrderService {
+    match keys {
+        Some(keys) => service.with_direct_redeem(keys),
+        None => service,
+    }
+}
+
+// Read a JSON argument that is either inline or a path to a file
+fn read_json_arg(value: &str) -> Result<String> {
+    if Path::new(value).is_file() {
+        std::fs::read_to_string(value).wrap_err_with(|| format!("Failed to read {}", value))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+pub async fn verify_sig(
+    order_id: Option<String>,
+    signature: Option<String>,
+    payload: Option<String>,
+    public_key: Option<String>,
+) -> Result<()> {
+    let mut payload = match (order_id, payload) {
+        (Some(order_id), _) => {
+            let signature =
+                signature.ok_or_else(|| eyre::eyre!("--signature is required with --order-id"))?;
+            println!(
+                "{}",
+                style(format!("🔍 Fetching order details for {}", order_id)).blue()
+            );
+            let params = order_service().initiate_params(&order_id).await?;
+            InitiateSignaturePayload {
+                order_id: Some(order_id),
+                params,
+                signature,
+                public_key: None,
+            }
+        }
+        (None, Some(raw)) => {
+            let mut payload: InitiateSignaturePayload = serde_json::from_str(&read_json_arg(&raw)?)
+                .wrap_err("Failed to parse signature payload")?;
+            if let Some(signature) = signature {
+                payload.signature = signature;
+            }
+            payload
+        }
+        (None, None) => return Err(eyre::eyre!("Either --order-id or --payload is required")),
+    };
+    if public_key.is_some() {
+        payload.public_key = public_key;
+    }
+
+    let check = verify_initiate_signature(&payload)?;
+    println!("{}", style(format!("📝 Digest: {}", check.digest)).dim());
+    println!(
+        "{}",
+        style(format!("👤 Expected signer: {}", check.expected)).dim()
+    );
+    println!("{}", style(format!("🔑 Signer: {}", check.signer)).dim());
+
+    if check.valid {
+        println!("{}", style("✅ Signature is valid").green().bold());
+        Ok(())
+    } else {
+        println!("{}", style("❌ Signature does not match").red().bold());
+        Err(eyre::eyre!("Signature verification failed"))
+    }
+}
+
+// Where dry-run payloads go: stdout, or one JSON file per payload in a directory
+pub struct DryRun {
+    dir: Option<PathBuf>,
+}
+
+impl DryRun {
+    pub fn new(enabled: bool, dir: Option<String>) -> Option<Self> {
+        enabled.then(|| Self {
+            dir: dir.map(PathBuf::from),
+        })
+    }
+
+    fn emit<T: Serialize>(&self, kind: &str, id: &str, value: &T) -> Result<()> {
+        let json = serde_json::to_string_pretty(value)?;
+        match &self.dir {
+            Some(dir) => {
+                std::fs::create_dir_all(dir)?;
+                let path = dir.join(format!("{}-{}.json", kind, id));
+                std::fs::write(&path, json + "\n")?;
+                println!(
+                    "{}",
+                    style(format!("📝 Wrote {} payload to {}", kind, path.display())).blue()
+                );
+            }
+            None => {
+                println!("{}", style(format!("📝 {} payload ({})", kind, id)).blue());
+                println!("{}", json);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Anything a batch command can report per-order progress for
+trait HasOrderId {
+    fn order_id(&self) -> &str;
+}
+
+impl HasOrderId for OrderData {
+    fn order_id(&self) -> &str {
+        &self.order_id
+    }
+}
+
+impl HasOrderId for InitiateBundleEntry {
+    fn order_id(&self) -> &str {
+        &self.order_id
+    }
+}
+
+// Run an action for every order with bounded concurrency and print a summary
+async fn for_each_order<T, F, Fut>(
+    orders: Vec<T>,
+    concurrency: usize,
+    action: &str,
+    f: F,
+) -> Result<()>
+where
+    T: HasOrderId,
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<String>> + Send + 'static,
+{
+    if orders.is_empty() {
+        return Err(eyre::eyre!("No orders found to {}", action));
+    }
+
+    let total = orders.len();
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = FuturesUnordered::new();
+
+    for order in orders {
+        let permit = semaphore.clone().acquire_owned().await?;
+        let order_id = order.order_id().to_string();
+        let fut = f(order);
+        tasks.push(tokio::spawn(async move {
+            let result = fut.await;
+            drop(permit);
+            (order_id, result)
+        }));
+    }
+
+    let mut successful = 0;
+    while let Some(result) = tasks.next().await {
+        match result {
+            Ok((order_id, Ok(output))) => {
+                successful += 1;
+                println!(
+                    "{}",
+                    style(format!("✅ {} order {}: {}", action, order_id, output)).green()
+                );
+            }
+            Ok((order_id, Err(e))) => {
+                println!(
+                    "{}",
+                    style(format!("❌ Failed to {} order {}: {}", action, order_id, e)).red()
+                );
+            }
+            Err(e) => {
+                println!("{}", style(format!("❌ Task error: {}", e)).red());
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        style(format!(
+            "📊 {}/{} orders processed ({:.2}%)",
+            successful,
+            total,
+            (successful as f64 / total as f64) * 100.0
+        ))
+        .bold()
+    );
+    Ok(())
+}
+
+// Report which quote version each created order was priced with
+pub fn print_quote_usage(quotes: &QuoteCache) {
+    println!("{}", style("📊 Quote usage:").blue());
+    for usage in quotes.usage() {
+        let quote = &usage.quote.quote;
+        println!(
+            "{}",
+            style(format!(
+                "  v{}: {} orders (strategy {}, source amount {}, destination amount {}, fetched at {})",
+                usage.quote.version,
+                usage.orders,
+                quote.best().strategy_id,
+                quote.best().source_amount,
+                quote.best().destination_amount,
+                chrono::DateTime::from_timestamp(quote.fetched_at, 0)
+                    .map(|at| at.to_rfc3339())
+                    .unwrap_or_default()
+            ))
+            .blue()
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create(
+    count: usize,
+    output: String,
+    source_chain: Option<String>,
+    destination_chain: Option<String>,
+    amount: Option<(String, bool)>,
+    order_params: OrderParamOverrides,
+    param_limits: Option<String>,
+    max_slippage_bps: Option<u32>,
+    quote_validity_secs: i64,
+    dry_run: Option<DryRun>,
+) -> Result<()> {
+    let dummy_quotes = load_dummy_orders(Path::new("data/dummy_orders.json"))
+        .wrap_err("Failed to load dummy quotes")?;
+    let mut quote = match (source_chain, destination_chain) {
+        (Some(src), Some(dst)) => find_quote_by_chains(&dummy_quotes, &src, &dst)
+            .ok_or_else(|| eyre::eyre!("No quote found for {} -> {}", src, dst))?,
+        _ => dummy_quotes
+            .quotes
+            .first()
+            .cloned()
+            .ok_or_else(|| eyre::eyre!("No quotes found in data/dummy_orders.json"))?,
+    };
+
+    if let Some((amount, exact_out)) = amount {
+        quote.amount = amount;
+        quote.exact_out = exact_out;
+    }
+
+    // Reject bad parameters once instead of failing every order
+    let (src, dst) = quote
+        .chains()
+        .ok_or_else(|| eyre::eyre!("Invalid order pair: {}", quote.order_pair))?;
+    let param_limits = param_limits
+        .map(ParamLimits::from_file)
+        .transpose()?
+        .unwrap_or_default();
+    let params = OrderParams::for_pair(src, dst, &order_params);
+    params.validate(src, dst, &param_limits)?;
+    println!(
+        "{}",
+        style(format!(
+            "⚙️ Timelock {} blocks, fee {}, {} destination confirmations, {} minute deadline",
+            params.timelock,
+            params.fee,
+            params.min_destination_confirmations,
+            params.deadline_minutes
+        ))
+        .blue()
+    );
+
+    let mut order_service = order_service()
+        .with_order_params(order_params)
+        .with_param_limits(param_limits)
+        .with_quote_validity(quote_validity_secs);
+    if let Some(bps) = max_slippage_bps {
+        order_service = order_service.with_max_slippage_bps(bps);
+    }
+    // Dry runs must not advance the nonces real orders will use
+    if dry_run.is_some() {
+        order_service = order_service.with_nonce_manager(NonceManager::in_memory());
+    }
+    println!(
+        "{}",
+        style(format!(
+            "🔍 Fetching quote for order pair: {}",
+            quote.order_pair
+        ))
+        .blue()
+    );
+    let quotes = QuoteCache::new(
+        order_service.quotes().clone(),
+        &quote.order_pair,
+        &quote.amount,
+        quote.exact_out,
+    );
+    // Fail fast if the pair cannot be quoted at all
+    let first = quotes.current().await?.quote;
+    if first.exact_out {
+        println!(
+            "{}",
+            style(format!(
+                "💰 Exact out: receiving {} requires sending {}",
+                first.best().destination_amount,
+                first.best().source_amount
+            ))
+            .green()
+        );
+    }
+
+    let mut created = Vec::new();
+    for order_num in 0..count {
+        let current = quotes.current().await?;
+        if let Some(dry_run) = &dry_run {
+            let (order, _secret) = order_service.build_order(&current.quote).await?;
+            let id = (order_num + 1).to_string();
+            dry_run.emit("order", &id, &order)?;
+            // Only the request; the relay signs the attestation when it is submitted
+            dry_run.emit("attestation_request", &id, &order.attestation_payload())?;
+            continue;
+        }
+
+        match order_service.create_order(&current.quote).await {
+            Ok((order_id, secret)) => {
+                quotes.record_use(current.version);
+                println!(
+                    "{}",
+                    style(format!(
+                        "✅ Created order {} of {} (ID: {})",
+                        order_num + 1,
+                        count,
+                        order_id
+                    ))
+                    .green()
+                );
+                created.push(OrderData { order_id, secret });
+            }
+            Err(e) => {
+                println!(
+                    "{}",
+                    style(format!(
+                        "❌ Failed to create order {} of {}: {}",
+                        order_num + 1,
+                        count,
+                        e
+                    ))
+                    .red()
+                );
+            }
+        }
+    }
+
+    if dry_run.is_none() {
+        print_quote_usage(&quotes);
+        file_utils::save_order_data_to(&output, &created)?;
+        println!(
+            "{}",
+            style(format!("💾 Saved {} orders to {}", created.len(), output)).green()
+        );
+    }
+    Ok(())
+}
+
+pub async fn initiate(
+    input: String,
+    private_key: String,
+    account: StarknetAccountConfig,
+    concurrency: usize,
+    dry_run: Option<DryRun>,
+) -> Result<()> {
+    let orders = file_utils::load_order_data_from(&input)?;
+    let order_service = order_service();
+    let dry_run = dry_run.map(Arc::new);
+
+    for_each_order(orders, concurrency, "initiate", |order| {
+        let order_service = order_service.clone();
+        let private_key = private_key.clone();
+        let account = account.clone();
+        let dry_run = dry_run.clone();
+        async move {
+            match dry_run {
+                Some(dry_run) => {
+                    let request = order_service
+                        .build_initiate_request(&order.order_id, &private_key, &account)
+                        .await?;
+                    dry_run.emit("initiate", &order.order_id, &request)?;
+                    Ok(String::from("dry run"))
+                }
+                None => {
+                    order_service
+                        .initiate_order(&order.order_id, &private_key, &account)
+                        .await
+                }
+            }
+        }
+    })
+    .await
+}
+
+pub async fn redeem(
+    input: String,
+    direct: Option<DirectRedeemKeys>,
+    concurrency: usize,
+    dry_run: Option<DryRun>,
+) -> Result<()> {
+    let orders = file_utils::load_order_data_from(&input)?;
+    let order_service = with_direct_redeem(order_service(), direct);
+    let dry_run = dry_run.map(Arc::new);
+
+    for_each_order(orders, concurrency, "redeem", |order| {
+        let order_service = order_service.clone();
+        let dry_run = dry_run.clone();
+        async move {
+            match dry_run {
+                Some(dry_run) => {
+                    let request =
+                        order_service.build_redeem_request(&order.order_id, &order.secret);
+                    dry_run.emit("redeem", &order.order_id, &request)?;
+                    Ok(String::from("dry run"))
+                }
+                None => order_service
+                    .retry_redeem_order(&order.order_id, &order.secret, 5)
+                    .await
+                    .map(|result| result.to_string()),
+            }
+        }
+    })
+    .await
+}
+
+pub async fn refund(
+    input: String,
+    direct: Option<DirectRedeemKeys>,
+    concurrency: usize,
+    dry_run: Option<DryRun>,
+) -> Result<()> {
+    let orders = file_utils::load_order_data_from(&input)?;
+    if direct.is_none() && dry_run.is_none() {
+        return Err(eyre::eyre!(
+            "Refunds are sent on chain, pass --evm-private-key or a Starknet account to pay the gas"
+        ));
+    }
+    let order_service = with_direct_redeem(order_service(), direct);
+    let dry_run = dry_run.map(Arc::new);
+
+    for_each_order(orders, concurrency, "refund", |order| {
+        let order_service = order_service.clone();
+        let dry_run = dry_run.clone();
+        async move {
+            match dry_run {
+                Some(dry_run) => {
+                    let refund = order_service.build_refund(&order.order_id).await?;
+                    dry_run.emit("refund", &order.order_id, &refund)?;
+                    Ok(String::from("dry run"))
+                }
+                None => order_service.refund_order(&order.order_id).await,
+            }
+        }
+    })
+    .await
+}
+
+pub async fn initiate_prepare(input: String, output: String, concurrency: usize) -> Result<()> {
+    let orders = file_utils::load_order_data_from(&input)?;
+    let order_service = order_service();
+
+    let results: Vec<_> = futures::stream::iter(orders)
+        .map(|order| {
+            let order_service = order_service.clone();
+            async move {
+                let params = order_service.initiate_params(&order.order_id).await;
+                (order.order_id, params)
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut entries = Vec::new();
+    for (order_id, result) in results {
+        match result {
+            Ok(params) => entries.push(InitiateBundleEntry {
+                order_id,
+                params,
+                signature: None,
+            }),
+            Err(e) => {
+                println!(
+                    "{}",
+                    style(format!("❌ Failed to prepare order {}: {}", order_id, e)).red()
+                );
+            }
+        }
+    }
+
+    let bundle = InitiateBundle {
+        prepared_at: chrono::Utc::now().timestamp(),
+        entries,
+    };
+    file_utils::save_json(&output, &bundle)?;
+    println!(
+        "{}",
+        style(format!(
+            "💾 Wrote {} unsigned orders to {}",
+            bundle.entries.len(),
+            output
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn initiate_sign(
+    input: String,
+    output: String,
+    private_key: Option<String>,
+    keystore: Option<String>,
+    account: StarknetAccountConfig,
+) -> Result<()> {
+    let private_key = match (private_key, keystore) {
+        (Some(private_key), _) => private_key,
+        (None, Some(keystore)) => {
+            let password = Password::new()
+                .with_prompt(style("🔑 Enter the keystore password").cyan().to_string())
+                .interact()?;
+            let signer = PrivateKeySigner::decrypt_keystore(&keystore, password)
+                .wrap_err_with(|| format!("Failed to decrypt keystore {}", keystore))?;
+            format!("0x{}", hex::encode(signer.to_bytes()))
+        }
+        (None, None) => {
+            return Err(eyre::eyre!(
+                "Either --private-key or --keystore is required"
+            ));
+        }
+    };
+
+    let mut bundle: InitiateBundle = file_utils::load_json(&input)?;
+    let mut signed = 0;
+    for entry in &mut bundle.entries {
+        match sign_initiate(&entry.params, &private_key, &account).await {
+            Ok(signature) => {
+                entry.signature = Some(signature);
+                signed += 1;
+                println!(
+                    "{}",
+                    style(format!("✍️ Signed order {}", entry.order_id)).green()
+                );
+            }
+            Err(e) => {
+                println!(
+                    "{}",
+                    style(format!("❌ Failed to sign order {}: {}", entry.order_id, e)).red()
+                );
+            }
+        }
+    }
+
+    file_utils::save_json(&output, &bundle)?;
+    println!(
+        "{}",
+        style(format!(
+            "💾 Wrote {}/{} signed orders to {}",
+            signed,
+            bundle.entries.len(),
+            output
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn initiate_submit(input: String, concurrency: usize) -> Result<()> {
+    let bundle: InitiateBundle = file_utils::load_json(&input)?;
+    let order_service = order_service();
+
+    for_each_order(bundle.entries, concurrency, "initiate", |entry| {
+        let order_service = order_service.clone();
+        async move {
+            let request = entry
+                .initiate_request()
+                .ok_or_else(|| eyre::eyre!("Order has not been signed"))?;
+            order_service.submit_initiate(&request).await
+        }
+    })
+    .await
+}
+
+pub async fn daemon(
+    input: String,
+    state: String,
+    interval: u64,
+    max_attempts: u32,
+    concurrency: usize,
+    once: bool,
+    direct: Option<DirectRedeemKeys>,
+) -> Result<()> {
+    let daemon = RedeemDaemon::new(with_direct_redeem(order_service(), direct))
+        .with_secrets_file(input)
+        .with_state_file(state)
+        .with_poll_interval(std::time::Duration::from_secs(interval.max(1)))
+        .with_max_attempts(max_attempts)
+        .with_concurrency(concurrency);
+
+    if once {
+        let summary = daemon.run_once().await?;
+        println!(
+            "{}",
+            style(format!(
+                "📊 {} orders watched: {} redeemed, {} refunded, {} failed, {} given up",
+                summary.watched,
+                summary.redeemed,
+                summary.refunded,
+                summary.failed,
+                summary.gave_up.len()
+            ))
+            .bold()
+        );
+        for order_id in &summary.gave_up {
+            println!(
+                "{}",
+                style(format!(
+                    "🛑 Gave up on order {}, see the state file",
+                    order_id
+                ))
+                .red()
+            );
+        }
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        style("👀 Watching orders, press Ctrl+C to stop").green()
+    );
+    tokio::select! {
+        result = daemon.run() => result,
+        _ = tokio::signal::ctrl_c() => {
+            println!("{}", style("🛑 Daemon stopped, progress is saved").yellow());
+            Ok(())
+        }
+    }
+}
+
+pub async fn recover_secrets(input: String, output: String, concurrency: usize) -> Result<()> {
+    if MASTER_SEED.get().is_none() {
+        return Err(eyre::eyre!("recover-secrets requires --master-seed"));
+    }
+    let order_ids: Vec<String> = file_utils::load_json(&input)?;
+    let total = order_ids.len();
+    let order_service = order_service();
+
+    let results: Vec<_> = futures::stream::iter(order_ids)
+        .map(|order_id| {
+            let order_service = order_service.clone();
+            async move {
+                let secret = order_service.recover_secret(&order_id).await;
+                (order_id, secret)
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut recovered = Vec::new();
+    for (order_id, result) in results {
+        match result {
+            Ok(secret) => recovered.push(OrderData { order_id, secret }),
+            Err(e) => {
+                println!(
+                    "{}",
+                    style(format!("❌ Failed to recover order {}: {}", order_id, e)).red()
+                );
+            }
+        }
+    }
+
+    file_utils::save_order_data_to(&output, &recovered)?;
+    println!(
+        "{}",
+        style(format!(
+            "💾 Recovered {}/{} secrets into {}",
+            recovered.len(),
+            total,
+            output
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn verify(input: String, concurrency: usize) -> Result<()> {
+    let orders = file_utils::load_order_data_from(&input)?;
+    if orders.is_empty() {
+        return Err(eyre::eyre!("No orders found to verify in {}", input));
+    }
+    let total = orders.len();
+    let order_service = order_service();
+
+    let mut results = futures::stream::iter(orders)
+        .map(|order| {
+            let order_service = order_service.clone();
+            async move {
+                let result = order_service
+                    .verify_order(&order.order_id, &order.secret)
+                    .await;
+                (order.order_id, result)
+            }
+        })
+        .buffer_unordered(concurrency.max(1));
+
+    let (mut verified, mut mismatched, mut missing) = (0, 0, 0);
+    while let Some((order_id, result)) = results.next().await {
+        match result {
+            Ok(OrderVerification::Verified(_)) => verified += 1,
+            Ok(OrderVerification::SecretHashMismatch { local, remote }) => {
+                mismatched += 1;
+                println!(
+                    "{}",
+                    style(format!(
+                        "❌ Order {}: local secret hash {} does not match API secret_hash {}",
+                        order_id, local, remote
+                    ))
+                    .red()
+                );
+            }
+            Err(e) => {
+                missing += 1;
+                println!(
+                    "{}",
+                    style(format!("❌ Failed to verify order {}: {:#}", order_id, e)).red()
+                );
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        style(format!(
+            "📊 {}/{} orders verified ({:.2}%), {} secret hash mismatches, {} missing or unmatched",
+            verified,
+            total,
+            (verified as f64 / total as f64) * 100.0,
+            mismatched,
+            missing
+        ))
+        .bold()
+    );
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn reconcile(
+    ids: String,
+    secrets: String,
+    state: String,
+    stuck_minutes: u64,
+    timelock_margin_minutes: u64,
+    output: Option<String>,
+    concurrency: usize,
+) -> Result<()> {
+    let report = Reconciler::new(order_service())
+        .with_ids_file(ids)
+        .with_secrets_file(secrets)
+        .with_state_file(state)
+        .with_thresholds(ReconcileThresholds {
+            stuck_after: std::time::Duration::from_secs(stuck_minutes * 60),
+            timelock_margin: std::time::Duration::from_secs(timelock_margin_minutes * 60),
+        })
+        .with_concurrency(concurrency)
+        .run()
+        .await?;
+
+    for entry in &report.entries {
+        let status = entry
+            .status
+            .map(|status| format!("{:?}", status))
+            .unwrap_or_else(|| "unknown".to_string());
+        for issue in &entry.issues {
+            let line = match issue {
+                ReconcileIssue::MissingSecret => "no stored secret".to_string(),
+                ReconcileIssue::InvalidSecret { error } => {
+                    format!("invalid stored secret: {}", error)
+                }
+                ReconcileIssue::SecretHashMismatch { local, remote } => format!(
+                    "local secret hash {} does not match API secret_hash {}",
+                    local, remote
+                ),
+                ReconcileIssue::Stuck { since_secs, .. } => {
+                    format!("stuck for {} minutes", since_secs / 60)
+                }
+                ReconcileIssue::TimelockRisk {
+                    leg,
+                    expires_in_blocks,
+                    expires_in_secs,
+                } if *expires_in_blocks <= 0 => format!(
+                    "{:?} timelock expired {} blocks (about {} minutes) ago",
+                    leg,
+                    -expires_in_blocks,
+                    -expires_in_secs / 60
+                ),
+                ReconcileIssue::TimelockRisk {
+                    leg,
+                    expires_in_blocks,
+                    expires_in_secs,
+                } => format!(
+                    "{:?} timelock expires in {} blocks (about {} minutes)",
+                    leg,
+                    expires_in_blocks,
+                    expires_in_secs / 60
+                ),
+                ReconcileIssue::Unreachable { error } => format!("not found in API: {}", error),
+            };
+            let line = format!("⚠️ {} [{}]: {}", entry.order_id, status, line);
+            match issue {
+                ReconcileIssue::Stuck { .. } => println!("{}", style(line).yellow()),
+                _ => println!("{}", style(line).red()),
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        style(format!(
+            "📊 {}/{} orders need attention: {} without secret, {} hash mismatches, {} stuck, {} near timelock expiry, {} not found",
+            report.entries.len(),
+            report.checked,
+            report.count(|issue| matches!(issue, ReconcileIssue::MissingSecret)),
+            report.count(|issue| matches!(
+                issue,
+                ReconcileIssue::SecretHashMismatch { .. } | ReconcileIssue::InvalidSecret { .. }
+            )),
+            report.count(|issue| matches!(issue, ReconcileIssue::Stuck { .. })),
+            report.count(|issue| matches!(issue, ReconcileIssue::TimelockRisk { .. })),
+            report.count(|issue| matches!(issue, ReconcileIssue::Unreachable { .. })),
+        ))
+        .bold()
+    );
+
+    if let Some(output) = output {
+        file_utils::save_json(&output, &report)?;
+        println!(
+            "{}",
+            style(format!("💾 Report saved to {}", output)).green()
+        );
+    }
+    Ok(())
+}
+
+// Unix time of a date, or of the last second of that day for `end_of_day`
+fn parse_date(value: &str, end_of_day: bool) -> Result<i64> {
+    if let Ok(time) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(time.timestamp());
+    }
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .wrap_err_with(|| format!("Invalid date {}, expected YYYY-MM-DD or RFC 3339", value))?;
+    let time = if end_of_day {
+        date.and_hms_opt(23, 59, 59)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    Ok(time.unwrap_or_default().and_utc().timestamp())
+}
+
+pub fn history_filter(
+    pair: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    status: Option<String>,
+    strategy: Option<String>,
+    min_amount: Option<String>,
+    max_amount: Option<String>,
+) -> Result<HistoryFilter> {
+    let amount = |amount: Option<String>| {
+        amount
+            .map(|amount| {
+                amount
+                    .parse()
+                    .wrap_err_with(|| format!("Invalid amount: {}", amount))
+            })
+            .transpose()
+    };
+    Ok(HistoryFilter {
+        pair,
+        from: from.map(|from| parse_date(&from, false)).transpose()?,
+        to: to.map(|to| parse_date(&to, true)).transpose()?,
+        status: status.map(|status| status.parse()).transpose()?,
+        strategy,
+        min_amount: amount(min_amount)?,
+        max_amount: amount(max_amount)?,
+    })
+}
+
+fn format_time(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+// Everything stored about one order, with its on-chain timeline
+fn print_history_entry(order_id: &str, entry: &HistoryEntry) {
+    let order = &entry.order.create_order;
+    println!("{}", style(format!("📋 Order {}", order_id)).bold());
+    println!("  Status:      {:?}", entry.status());
+    println!("  Created:     {}", format_time(entry.created_at()));
+    println!(
+        "  Source:      {} {} on {}",
+        order.source_amount, order.source_asset, order.source_chain
+    );
+    println!(
+        "  Destination: {} {} on {}",
+        order.destination_amount, order.destination_asset, order.destination_chain
+    );
+    println!("  Strategy:    {}", order.additional_data.strategy_id);
+    println!("  Secret hash: {}", order.secret_hash);
+    println!("  Refreshed:   {}", format_time(entry.refreshed_at));
+
+    let timeline = entry.order.timeline();
+    if timeline.is_empty() {
+        println!("  Timeline:    nothing on chain yet");
+        return;
+    }
+    println!("  Timeline:");
+    for event in timeline {
+        let block = event
+            .block_number
+            .map(|block| format!("block {}", block))
+            .unwrap_or_else(|| "block unknown".to_string());
+        println!(
+            "    {:?} {:?} on {}: {} ({})",
+            event.leg, event.step, event.chain, event.tx_hash, block
+        );
+    }
+}
+
+// Fetch one order, or every order in the history and the local order files
+async fn refresh_history(
+    history: &mut OrderHistory,
+    order_id: Option<String>,
+    concurrency: usize,
+) -> Result<()> {
+    let order_ids: Vec<String> = match order_id {
+        Some(order_id) => vec![order_id],
+        None => {
+            let mut order_ids: std::collections::BTreeSet<String> =
+                history.order_ids().cloned().collect();
+            order_ids.extend(file_utils::load_order_ids().unwrap_or_default());
+            order_ids.extend(
+                file_utils::load_order_data()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|order| order.order_id),
+            );
+            order_ids.into_iter().collect()
+        }
+    };
+    let total = order_ids.len();
+    let updated = history
+        .refresh(&order_service(), order_ids, concurrency)
+        .await?;
+    println!(
+        "{}",
+        style(format!(
+            "🔄 Refreshed {}/{} orders from the API",
+            updated, total
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn history(
+    order_id: Option<String>,
+    filter: HistoryFilter,
+    page: usize,
+    page_size: usize,
+    refresh: bool,
+    path: String,
+    concurrency: usize,
+) -> Result<()> {
+    let mut history = OrderHistory::open(&path)?;
+
+    if refresh {
+        refresh_history(&mut history, order_id.clone(), concurrency).await?;
+    }
+
+    if let Some(order_id) = order_id {
+        let entry = history.get(&order_id).ok_or_else(|| {
+            eyre::eyre!(
+                "Order {} is not in {}, run with --refresh to fetch it",
+                order_id,
+                path
+            )
+        })?;
+        print_history_entry(&order_id, entry);
+        return Ok(());
+    }
+
+    if history.is_empty() {
+        println!(
+            "{}",
+            style(format!(
+                "📭 No orders in {}, run with --refresh to fetch stored orders",
+                path
+            ))
+            .yellow()
+        );
+        return Ok(());
+    }
+
+    let page = history.query(&filter, page, page_size);
+    for (order_id, entry) in &page.entries {
+        let order = &entry.order.create_order;
+        println!(
+            "{}  {}  {} → {}  {} → {}  {:?}  {}",
+            format_time(entry.created_at()),
+            order_id,
+            order.source_chain,
+            order.destination_chain,
+            order.source_amount,
+            order.destination_amount,
+            entry.status(),
+            order.additional_data.strategy_id
+        );
+    }
+    println!(
+        "{}",
+        style(format!(
+            "📄 Page {}/{}, {} of {} stored orders match",
+            page.page,
+            page.total_pages,
+            page.total,
+            history.len()
+        ))
+        .bold()
+    );
+    Ok(())
+}
+
+pub async fn export(
+    output: String,
+    from: Option<String>,
+    to: Option<String>,
+    refresh: bool,
+    history_path: String,
+    concurrency: usize,
+) -> Result<()> {
+    let format = LedgerFormat::from_path(&output)?;
+    let filter = history_filter(None, from, to, None, None, None, None)?;
+    let mut history = OrderHistory::open(&history_path)?;
+    if refresh {
+        refresh_history(&mut history, None, concurrency).await?;
+    }
+
+    // Oldest first, the order a ledger is read in
+    let page = history.query(&filter, 1, usize::MAX);
+    let rows: Vec<LedgerRow> = page
+        .entries
+        .iter()
+        .rev()
+        .map(|(order_id, entry)| LedgerRow::from_entry(order_id, entry))
+        .collect();
+    write_ledger(&output, format, &rows)?;
+
+    println!(
+        "{}",
+        style(format!(
+            "💾 Exported {} of {} stored orders to {}",
+            rows.len(),
+            history.len(),
+            output
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn user_orders(
+    address: String,
+    page: u32,
+    per_page: u32,
+    import: bool,
+    history_path: String,
+) -> Result<()> {
+    let order_service = order_service();
+
+    if !import {
+        let listing = order_service.user_orders(&address, page, per_page).await?;
+        for order in &listing.data {
+            let create_order = &order.create_order;
+            println!(
+                "{}  {} → {}  {} → {}  {:?}",
+                create_order.create_id,
+                create_order.source_chain,
+                create_order.destination_chain,
+                create_order.source_amount,
+                create_order.destination_amount,
+                order.status()
+            );
+        }
+        println!(
+            "{}",
+            style(format!(
+                "📄 Page {}/{}, {} orders for {}",
+                listing.page, listing.total_pages, listing.total_items, address
+            ))
+            .bold()
+        );
+        return Ok(());
+    }
+
+    let orders = order_service.all_user_orders(&address, per_page).await?;
+    let order_ids: Vec<String> = orders
+        .iter()
+        .map(|order| order.create_order.create_id.clone())
+        .collect();
+    let added = file_utils::add_order_ids(&order_ids)?;
+
+    let mut history = OrderHistory::open(&history_path)?;
+    let now = chrono::Utc::now().timestamp();
+    for order in orders {
+        let order_id = order.create_order.create_id.clone();
+        history.insert(&order_id, order, now);
+    }
+    history.save()?;
+
+    println!(
+        "{}",
+        style(format!(
+            "📥 Imported {} orders for {}: {} newly tracked in data/order_ids.json, all saved to {}",
+            order_ids.len(),
+            address,
+            added,
+            history_path
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn status(order_id: String) -> Result<()> {
+    let status = order_service().order_status(&order_id).await?;
+    println!(
+        "{}",
+        style(format!("📋 Order {}: {:?}", order_id, status)).green()
+    );
+    Ok(())
+}
@@ -1,16 +1,25 @@
 pub mod args;
+pub mod commands;
 pub mod interactive;
 
-use crate::services::order_service::OrderService;
-use crate::utils::dummy_orders::{
-    find_quote_by_chains, list_available_chain_pairs, load_dummy_orders,
-};
+use crate::cli::args::{Args, Commands, InitiateStep};
+use crate::cli::commands::DryRun;
+
 use alloy::hex::ToHexExt;
+use clap::Parser;
 use console::Term;
 use console::style;
 use dialoguer::{Confirm, Input, Select};
 use eyre::Result;
 use futures::{StreamExt, stream::FuturesUnordered};
+use garden_sdk::models::order_params::OrderParamOverrides;
+use garden_sdk::services::order_service::OrderService;
+use garden_sdk::services::quote_cache::{QuoteCache, VersionedQuote};
+use garden_sdk::services::starknet_services::StarknetAccountConfig;
+use garden_sdk::utils::dummy_orders::{
+    find_quote_by_chains, list_available_chain_pairs, load_dummy_orders,
+};
+use std::io::Write;
 use std::path::Path;
 use std::process;
 use std::sync::Arc;
@@ -19,27 +28,252 @@ use tracing::info;
 pub async fn run() -> Result<()> {
     // Initialize tracing
     tracing_subscriber::fmt::init();
-    let term = Term::stdout();
+    let args = Args::parse();
+    commands::init_garden_api(args.record, args.replay)?;
+    commands::init_master_seed(args.master_seed)?;
+
+    match args.command {
+        Some(Commands::VerifySig {
+            order_id,
+            signature,
+            payload,
+            public_key,
+        }) => commands::verify_sig(order_id, signature, payload, public_key).await,
+        Some(Commands::Create {
+            count,
+            output,
+            source_chain,
+            destination_chain,
+            amount,
+            exact_out,
+            timelock,
+            fee,
+            min_confirmations,
+            deadline_minutes,
+            param_limits,
+            max_slippage_bps,
+            quote_validity_secs,
+            dry_run,
+            dry_run_dir,
+        }) => {
+            commands::create(
+                count,
+                output,
+                source_chain,
+                destination_chain,
+                amount.map(|amount| (amount, exact_out)),
+                OrderParamOverrides {
+                    timelock,
+                    fee,
+                    min_destination_confirmations: min_confirmations,
+                    deadline_minutes,
+                },
+                param_limits,
+                max_slippage_bps,
+                quote_validity_secs,
+                DryRun::new(dry_run, dry_run_dir),
+            )
+            .await
+        }
+        Some(Commands::Initiate {
+            step: Some(step), ..
+        }) => match step {
+            InitiateStep::Prepare {
+                input,
+                output,
+                concurrency,
+            } => commands::initiate_prepare(input, output, concurrency).await,
+            InitiateStep::Sign {
+                input,
+                output,
+                private_key,
+                keystore,
+                account_type,
+                guardian_key,
+            } => {
+                let account = StarknetAccountConfig {
+                    account_type,
+                    guardian_key,
+                };
+                commands::initiate_sign(input, output, private_key, keystore, account).await
+            }
+            InitiateStep::Submit { input, concurrency } => {
+                commands::initiate_submit(input, concurrency).await
+            }
+        },
+        Some(Commands::Initiate {
+            step: None,
+            input,
+            private_key,
+            account_type,
+            guardian_key,
+            concurrency,
+            dry_run,
+            dry_run_dir,
+        }) => {
+            let private_key =
+                private_key.ok_or_else(|| eyre::eyre!("--private-key is required"))?;
+            let account = StarknetAccountConfig {
+                account_type,
+                guardian_key,
+            };
+            commands::initiate(
+                input,
+                private_key,
+                account,
+                concurrency,
+                DryRun::new(dry_run, dry_run_dir),
+            )
+            .await
+        }
+        Some(Commands::Redeem {
+            input,
+            direct,
+            concurrency,
+            dry_run,
+            dry_run_dir,
+        }) => {
+            commands::redeem(
+                input,
+                direct.keys(),
+                concurrency,
+                DryRun::new(dry_run, dry_run_dir),
+            )
+            .await
+        }
+        Some(Commands::Refund {
+            input,
+            direct,
+            concurrency,
+            dry_run,
+            dry_run_dir,
+        }) => {
+            commands::refund(
+                input,
+                direct.keys(),
+                concurrency,
+                DryRun::new(dry_run, dry_run_dir),
+            )
+            .await
+        }
+        Some(Commands::Daemon {
+            input,
+            state,
+            interval,
+            max_attempts,
+            concurrency,
+            once,
+            direct,
+        }) => {
+            commands::daemon(
+                input,
+                state,
+                interval,
+                max_attempts,
+                concurrency,
+                once,
+                direct.keys(),
+            )
+            .await
+        }
+        Some(Commands::RecoverSecrets {
+            input,
+            output,
+            concurrency,
+        }) => commands::recover_secrets(input, output, concurrency).await,
+        Some(Commands::Verify { input, concurrency }) => commands::verify(input, concurrency).await,
+        Some(Commands::Reconcile {
+            ids,
+            secrets,
+            state,
+            stuck_minutes,
+            timelock_margin_minutes,
+            output,
+            concurrency,
+        }) => {
+            commands::reconcile(
+                ids,
+                secrets,
+                state,
+                stuck_minutes,
+                timelock_margin_minutes,
+                output,
+                concurrency,
+            )
+            .await
+        }
+        Some(Commands::History {
+            order_id,
+            pair,
+            from,
+            to,
+            status,
+            strategy,
+            min_amount,
+            max_amount,
+            page,
+            page_size,
+            refresh,
+            history,
+            concurrency,
+        }) => {
+            let filter =
+                commands::history_filter(pair, from, to, status, strategy, min_amount, max_amount)?;
+            commands::history(
+                order_id,
+                filter,
+                page,
+                page_size,
+                refresh,
+                history,
+                concurrency,
+            )
+            .await
+        }
+        Some(Commands::Export {
+            output,
+            from,
+            to,
+            refresh,
+            history,
+            concurrency,
+        }) => commands::export(output, from, to, refresh, history, concurrency).await,
+        Some(Commands::UserOrders {
+            address,
+            page,
+            per_page,
+            import,
+            history,
+        }) => commands::user_orders(address, page, per_page, import, history).await,
+        Some(Commands::Status { order_id }) => commands::status(order_id).await,
+        None => run_interactive().await,
+    }
+}
+
+async fn run_interactive() -> Result<()> {
+    let mut term = Term::stdout();
 
     // 🌱 Welcome message
-    term.write_line(
-        &style("🌼 Welcome to the Garden SDK CLI Application!")
+    writeln!(
+        term,
+        "{}",
+        style("🌼 Welcome to the Garden SDK CLI Application!")
             .green()
             .bold()
-            .to_string(),
     )?;
-    term.write_line(
-        &style("🌿 This is a garden of features, ready to bloom!")
+    writeln!(
+        term,
+        "{}",
+        style("🌿 This is a garden of features, ready to bloom!")
             .yellow()
             .dim()
-            .to_string(),
     )?;
     term.write_line("")?;
 
     // 🌟 Start confirmation
     if !Confirm::new()
         .with_prompt(
-            &style("🚀 Do you want to start the order creation process?")
+            style("🚀 Do you want to start the order creation process?")
                 .green()
                 .bold()
                 .to_string(),
@@ -47,14 +281,14 @@ pub async fn run() -> Result<()> {
         .default(true)
         .interact()?
     {
-        term.write_line(&style("👋 Exiting application...").red().to_string())?;
+        writeln!(term, "{}", style("👋 Exiting application...").red())?;
         return Ok(());
     }
 
     // 🧑‍💼 Get number of clients (coroutines)
     let num_clients: u32 = Input::new()
         .with_prompt(
-            &style("👥 How many clients do you want to create?")
+            style("👥 How many clients do you want to create?")
                 .cyan()
                 .to_string(),
         )
@@ -62,7 +296,7 @@ pub async fn run() -> Result<()> {
 
     // 📦 Get number of orders per client
     let orders_per_client: u32 = Input::new()
-        .with_prompt(&style("📦 How many orders per client?").cyan().to_string())
+        .with_prompt(style("📦 How many orders per client?").cyan().to_string())
         .interact_text()?;
 
     // 📄 Load dummy orders
@@ -87,7 +321,7 @@ pub async fn run() -> Result<()> {
 
     let selection = Select::new()
         .with_prompt(
-            &style("🔗 Select source chain -> destination chain")
+            style("🔗 Select source chain -> destination chain")
                 .blue()
                 .to_string(),
         )
@@ -118,7 +352,7 @@ pub async fn run() -> Result<()> {
     );
 
     if !Confirm::new()
-        .with_prompt(&style(prompt).magenta().bold().to_string())
+        .with_prompt(style(prompt).magenta().bold().to_string())
         .default(true)
         .interact()?
     {
@@ -127,7 +361,7 @@ pub async fn run() -> Result<()> {
     }
 
     // Initialize the order service
-    let order_service = OrderService::new();
+    let order_service = commands::order_service();
     let mut order_ids: Vec<(String, String)> = Vec::new(); // (order_id, secret)
 
     println!("{}", style("📦 Creating orders...").yellow());
@@ -144,17 +378,22 @@ pub async fn run() -> Result<()> {
         .blue()
     );
 
-    // Get quote for the order
-    match order_service
-        .get_quote(&quote.order_pair, &quote.amount, quote.exact_out)
-        .await
-    {
-        Ok((strategy_id, input_price, output_price, destination_amount)) => {
+    // Get quote for the order; the cache re-quotes as orders are created
+    let quotes = QuoteCache::new(
+        order_service.quotes().clone(),
+        &quote.order_pair,
+        &quote.amount,
+        quote.exact_out,
+    );
+    match quotes.current().await {
+        Ok(VersionedQuote {
+            quote: live_quote, ..
+        }) => {
             println!(
                 "{}",
                 style(format!(
                     "✅ Quote received with strategy ID: {}",
-                    strategy_id
+                    live_quote.best().strategy_id
                 ))
                 .green()
             );
@@ -162,33 +401,29 @@ pub async fn run() -> Result<()> {
                 "{}",
                 style(format!(
                     "💰 Input token price: {}, Output token price: {}",
-                    input_price, output_price
+                    live_quote.input_token_price, live_quote.output_token_price
                 ))
                 .green()
             );
 
             println!(
                 "{}",
-                style(format!("💰 Destination amount: {}", destination_amount)).green()
+                style(format!(
+                    "💰 Source amount: {}, Destination amount: {}",
+                    live_quote.best().source_amount,
+                    live_quote.best().destination_amount
+                ))
+                .green()
             );
 
             // Create orders based on the quote
             let mut tasks = FuturesUnordered::new();
-            let strategy_id = strategy_id.clone(); // Clone before the loop
-
-            // Clone order_pair, amount, and destination_amount outside the loop
-            let order_pair = quote.order_pair.clone();
-            let amount = quote.amount.clone();
-            let destination_amount = destination_amount.clone();
 
             // Process each client (coroutine)
             for client_id in 0..num_clients {
                 let order_service_clone = order_service.clone();
                 let semaphore_clone = semaphore.clone();
-                let strategy_id = strategy_id.clone(); // Clone for each client
-                let order_pair = order_pair.clone();
-                let amount = amount.clone();
-                let destination_amount = destination_amount.clone(); // Clone for each client
+                let quotes = quotes.clone(); // Clone for each client
 
                 tasks.push(tokio::spawn(async move {
                     let mut results = Vec::new();
@@ -203,19 +438,16 @@ pub async fn run() -> Result<()> {
                     // Process orders for this client
                     for order_num in 0..orders_per_client {
                         let permit = semaphore_clone.clone().acquire_owned().await.unwrap();
-                        match order_service_clone
-                            .create_order(
-                                strategy_id.clone(),
-                                input_price,
-                                output_price,
-                                &order_pair,
-                                &amount,
-                                quote.exact_out,
-                                destination_amount.clone(),
-                            )
-                            .await
-                        {
-                            Ok((order_id, secret)) => {
+                        let created = match quotes.current().await {
+                            Ok(current) => order_service_clone
+                                .create_order(&current.quote)
+                                .await
+                                .map(|created| (created, current.version)),
+                            Err(e) => Err(e),
+                        };
+                        match created {
+                            Ok(((order_id, secret), version)) => {
+                                quotes.record_use(version);
                                 println!(
                                     "{}",
                                     style(format!(
@@ -276,6 +508,7 @@ pub async fn run() -> Result<()> {
                     }
                 }
             }
+            commands::print_quote_usage(&quotes);
         }
         Err(e) => {
             println!("{}", style(format!("❌ Failed to get quote: {}", e)).red());
@@ -286,7 +519,7 @@ pub async fn run() -> Result<()> {
     // 🔧 Initiate Orders
     if Confirm::new()
         .with_prompt(
-            &style("⚙️ Do you want to initiate the created orders?")
+            style("⚙️ Do you want to initiate the created orders?")
                 .cyan()
                 .to_string(),
         )
@@ -298,14 +531,14 @@ pub async fn run() -> Result<()> {
         // Get private key for signing
         let private_key: String = Input::new()
             .with_prompt(
-                &style("🔑 Enter your private key (hex format)")
+                style("🔑 Enter your private key (hex format)")
                     .cyan()
                     .to_string(),
             )
             .interact_text()?;
 
         // Initialize the order service
-        let order_service = OrderService::new();
+        let order_service = commands::order_service();
 
         // Create a semaphore to limit concurrent requests
         let semaphore = Arc::new(tokio::sync::Semaphore::new(num_clients as usize));
@@ -321,7 +554,11 @@ pub async fn run() -> Result<()> {
 
             tasks.push(tokio::spawn(async move {
                 let result = order_service_clone
-                    .initiate_order(&order_id_clone, &private_key_clone)
+                    .initiate_order(
+                        &order_id_clone,
+                        &private_key_clone,
+                        &StarknetAccountConfig::default(),
+                    )
                     .await;
                 drop(permit);
                 (order_id_clone, result)
@@ -354,7 +591,7 @@ pub async fn run() -> Result<()> {
     // 🎁 Redeem Orders
     if Confirm::new()
         .with_prompt(
-            &style("🎉 Do you want to redeem the orders?")
+            style("🎉 Do you want to redeem the orders?")
                 .cyan()
                 .to_string(),
         )
@@ -364,7 +601,7 @@ pub async fn run() -> Result<()> {
         println!("{}", style("💸 Redeeming orders...").yellow());
 
         // Initialize the order service
-        let order_service = OrderService::new();
+        let order_service = commands::order_service();
 
         // Create a semaphore to limit concurrent requests
         let semaphore = Arc::new(tokio::sync::Semaphore::new(num_clients as usize));
@@ -389,10 +626,10 @@ pub async fn run() -> Result<()> {
 
         while let Some(result) = tasks.next().await {
             match result {
-                Ok((order_id, Ok(tx_hash))) => {
+                Ok((order_id, Ok(result))) => {
                     println!(
                         "{}",
-                        style(format!("✅ Redeemed order {}: {}", order_id, tx_hash)).green()
+                        style(format!("✅ Redeemed order {}: {}", order_id, result)).green()
                     );
                 }
                 Ok((order_id, Err(e))) => {
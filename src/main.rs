@@ -1,10 +1,6 @@
 #![allow(unused)]
 
 mod cli;
-mod config;
-mod models;
-mod services;
-mod utils;
 
 use crate::cli::run;
 use eyre::Result;
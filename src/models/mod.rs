@@ -1,3 +0,0 @@
-pub mod additional_data;
-pub mod order;
-pub mod quote;
@@ -1,4 +0,0 @@
-pub mod db_service;
-pub mod order_service;
-pub mod quote_service;
-pub mod starknet_services;